@@ -0,0 +1,135 @@
+//! Environment variable interpolation for configuration values
+//!
+//! Expands `${VAR}` and `$VAR` references in configuration strings. A `$$`
+//! sequence produces a literal `$`, and a `$` not followed by a variable name
+//! is kept as is.
+
+use crate::error::{ErrorContext, PixlieError, Result};
+
+/// Expand variable references in `value`, resolving names with `lookup`
+///
+/// `field` names the configuration field being expanded and is used in
+/// errors for undefined variables or malformed references.
+pub fn interpolate<F>(field: &str, value: &str, lookup: F) -> Result<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
+        }
+
+        let name = match chars.peek() {
+            Some('$') => {
+                chars.next();
+                result.push('$');
+                continue;
+            }
+            Some('{') => {
+                chars.next();
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(PixlieError::validation(
+                                field.to_string(),
+                                format!("Unterminated variable reference '${{{}'", name),
+                                ErrorContext::new().with_context("Variable interpolation"),
+                            ))
+                        }
+                    }
+                }
+
+                if !is_variable_name(&name) {
+                    return Err(PixlieError::validation(
+                        field.to_string(),
+                        format!("Invalid variable name '{}'", name),
+                        ErrorContext::new().with_context("Variable interpolation"),
+                    ));
+                }
+                name
+            }
+            Some(&c) if c == '_' || c.is_ascii_alphabetic() => {
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '_' || c.is_ascii_alphanumeric() {
+                        name.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                name
+            }
+            _ => {
+                result.push('$');
+                continue;
+            }
+        };
+
+        let value = lookup(&name).ok_or_else(|| {
+            PixlieError::validation(
+                field.to_string(),
+                format!("Undefined environment variable '{}'", name),
+                ErrorContext::new().with_context("Variable interpolation"),
+            )
+        })?;
+        result.push_str(&value);
+    }
+
+    Ok(result)
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn vars() -> HashMap<String, String> {
+        HashMap::from([
+            ("DATA_DIR".to_string(), "/srv/data".to_string()),
+            ("DB".to_string(), "sales.db".to_string()),
+        ])
+    }
+
+    fn expand(value: &str) -> Result<String> {
+        let vars = vars();
+        interpolate("test", value, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_interpolate_braced_and_bare() {
+        assert_eq!(expand("${DATA_DIR}/$DB").unwrap(), "/srv/data/sales.db");
+        assert_eq!(expand("${DATA_DIR}_backup").unwrap(), "/srv/data_backup");
+        assert_eq!(expand("no variables").unwrap(), "no variables");
+    }
+
+    #[test]
+    fn test_interpolate_escapes_and_literals() {
+        assert_eq!(expand("cost: $$5").unwrap(), "cost: $5");
+        assert_eq!(expand("$${DATA_DIR}").unwrap(), "${DATA_DIR}");
+        assert_eq!(expand("trailing $").unwrap(), "trailing $");
+        assert_eq!(expand("$1").unwrap(), "$1");
+    }
+
+    #[test]
+    fn test_interpolate_errors() {
+        let err = expand("${MISSING}/data").unwrap_err();
+        assert!(err.to_string().contains("MISSING"));
+
+        assert!(expand("${DATA_DIR").is_err());
+        assert!(expand("${1BAD}").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! Key binding parsing for keyboard shortcuts
+//!
+//! Shortcut strings are modifiers and a key joined by `+`, e.g. `Ctrl+Q`,
+//! `Shift+Tab`, `Alt+Enter` or `F5`. Modifier and key names are
+//! case-insensitive; a single character key is matched as written unless
+//! combined with Ctrl or Alt, where letters are matched without case.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// A parsed keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Parse a shortcut string, returning `None` for unknown keys or modifiers
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (modifier_part, key_part) = if value == "+" {
+            ("", "+")
+        } else if let Some(modifiers) = value.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            match value.rsplit_once('+') {
+                Some((modifiers, key)) => (modifiers, key),
+                None => ("", value),
+            }
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        if !modifier_part.is_empty() {
+            for name in modifier_part.split('+') {
+                modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => KeyModifiers::CONTROL,
+                    "alt" | "option" => KeyModifiers::ALT,
+                    "shift" => KeyModifiers::SHIFT,
+                    "super" | "cmd" | "win" => KeyModifiers::SUPER,
+                    "meta" => KeyModifiers::META,
+                    _ => return None,
+                };
+            }
+        }
+
+        let code = parse_key_code(key_part.trim())?;
+        Some(Self::normalized(code, modifiers))
+    }
+
+    /// Whether a terminal key event triggers this binding
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        *self == Self::normalized(event.code, event.modifiers)
+    }
+
+    /// Canonical form so bindings and terminal events compare equal
+    ///
+    /// Terminals report Shift inconsistently: Shift+Tab arrives as `BackTab`
+    /// and shifted characters arrive already uppercased, with or without the
+    /// SHIFT flag. Shift is folded into the key wherever it is implied.
+    fn normalized(code: KeyCode, mut modifiers: KeyModifiers) -> Self {
+        let shift = modifiers.contains(KeyModifiers::SHIFT);
+        let code = match code {
+            KeyCode::Tab if shift => KeyCode::BackTab,
+            KeyCode::Char(c) => {
+                let folded = if shift {
+                    c.to_ascii_uppercase()
+                } else if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                };
+                KeyCode::Char(folded)
+            }
+            code => code,
+        };
+
+        if matches!(code, KeyCode::BackTab | KeyCode::Char(_)) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        Self { code, modifiers }
+    }
+}
+
+fn parse_key_code(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let lower = name.to_ascii_lowercase();
+    let code = match lower.as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        _ => {
+            let number: u8 = lower.strip_prefix('f')?.parse().ok()?;
+            if !(1..=24).contains(&number) {
+                return None;
+            }
+            KeyCode::F(number)
+        }
+    };
+
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_parse_key_bindings() {
+        let ctrl_q = KeyBinding::parse("Ctrl+Q").unwrap();
+        assert_eq!(ctrl_q.code, KeyCode::Char('q'));
+        assert_eq!(ctrl_q.modifiers, KeyModifiers::CONTROL);
+
+        assert_eq!(KeyBinding::parse("Tab").unwrap().code, KeyCode::Tab);
+        assert_eq!(KeyBinding::parse("F12").unwrap().code, KeyCode::F(12));
+        assert_eq!(
+            KeyBinding::parse("Ctrl+,").unwrap().code,
+            KeyCode::Char(',')
+        );
+        assert_eq!(
+            KeyBinding::parse("ctrl++").unwrap().code,
+            KeyCode::Char('+')
+        );
+
+        let alt_enter = KeyBinding::parse("alt+enter").unwrap();
+        assert_eq!(alt_enter.code, KeyCode::Enter);
+        assert_eq!(alt_enter.modifiers, KeyModifiers::ALT);
+
+        assert!(KeyBinding::parse("Ctrl+Nope").is_none());
+        assert!(KeyBinding::parse("Hyper+Q").is_none());
+        assert!(KeyBinding::parse("F25").is_none());
+        assert!(KeyBinding::parse("").is_none());
+    }
+
+    #[test]
+    fn test_matches_terminal_events() {
+        let ctrl_q = KeyBinding::parse("Ctrl+Q").unwrap();
+        assert!(ctrl_q.matches(&event(KeyCode::Char('q'), KeyModifiers::CONTROL)));
+        assert!(!ctrl_q.matches(&event(KeyCode::Char('q'), KeyModifiers::NONE)));
+
+        let shift_tab = KeyBinding::parse("Shift+Tab").unwrap();
+        assert!(shift_tab.matches(&event(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert!(shift_tab.matches(&event(KeyCode::BackTab, KeyModifiers::NONE)));
+
+        let upper_q = KeyBinding::parse("Q").unwrap();
+        assert!(upper_q.matches(&event(KeyCode::Char('Q'), KeyModifiers::SHIFT)));
+        assert!(!upper_q.matches(&event(KeyCode::Char('q'), KeyModifiers::NONE)));
+    }
+}
@@ -4,7 +4,9 @@
 
 use super::{CliArgs, ConfigPaths, GlobalConfig, WorkspaceConfig};
 use crate::error::{ErrorContext, ErrorContextExt, Result};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, info, warn};
 
 /// Configuration loader with support for multiple sources and precedence
@@ -20,6 +22,11 @@ impl ConfigLoader {
         Ok(Self { paths })
     }
 
+    /// Create a configuration loader reading from explicit paths
+    pub fn with_paths(paths: ConfigPaths) -> Self {
+        Self { paths }
+    }
+
     /// Load global configuration from file
     pub async fn load_global_config(&self) -> Result<GlobalConfig> {
         let context = ErrorContext::new().with_context("Global configuration loading");
@@ -48,12 +55,38 @@ impl ConfigLoader {
     }
 
     /// Load workspace configuration from file
+    ///
+    /// Variable references in the workspace's values are expanded from the
+    /// process environment and the workspace's own `environment` map.
     pub async fn load_workspace_config<P: AsRef<Path>>(
         &self,
         workspace_path: P,
     ) -> Result<Option<WorkspaceConfig>> {
         let context = ErrorContext::new().with_context("Workspace configuration loading");
 
+        let Some(mut config) = self.read_workspace_config(workspace_path).await? else {
+            return Ok(None);
+        };
+
+        config
+            .expand_variables(|name| std::env::var(name).ok())
+            .with_context(|| context.clone())?;
+        config.validate().with_context(|| context.clone())?;
+
+        info!("Workspace configuration loaded successfully");
+        Ok(Some(config))
+    }
+
+    /// Read workspace configuration from file without expanding variables
+    ///
+    /// Used when the configuration is going to be written back, so the file
+    /// keeps its `${VAR}` references.
+    pub async fn read_workspace_config<P: AsRef<Path>>(
+        &self,
+        workspace_path: P,
+    ) -> Result<Option<WorkspaceConfig>> {
+        let context = ErrorContext::new().with_context("Workspace configuration loading");
+
         let workspace_config_path = workspace_path.as_ref().join(".pixlie-workspace.toml");
 
         if !workspace_config_path.exists() {
@@ -75,7 +108,6 @@ impl ConfigLoader {
 
         config.validate().with_context(|| context.clone())?;
 
-        info!("Workspace configuration loaded successfully");
         Ok(Some(config))
     }
 
@@ -96,7 +128,7 @@ impl ConfigLoader {
         let content = toml::to_string_pretty(config)
             .with_context_msg("Failed to serialize global configuration to TOML")?;
 
-        tokio::fs::write(&self.paths.global_config, content)
+        write_atomic(&self.paths.global_config, &content)
             .await
             .with_context(|| context.clone())?;
 
@@ -107,31 +139,14 @@ impl ConfigLoader {
         Ok(())
     }
 
-    /// Save workspace configuration to file
+    /// Save workspace configuration to file, updating its last modified time
     pub async fn save_workspace_config<P: AsRef<Path>>(
         &self,
         workspace_path: P,
-        config: &WorkspaceConfig,
+        config: &mut WorkspaceConfig,
     ) -> Result<()> {
-        let context = ErrorContext::new().with_context("Workspace configuration saving");
-
         let workspace_config_path = workspace_path.as_ref().join(".pixlie-workspace.toml");
-
-        // Validate before saving
-        config.validate().with_context(|| context.clone())?;
-
-        let content = toml::to_string_pretty(config)
-            .with_context_msg("Failed to serialize workspace configuration to TOML")?;
-
-        tokio::fs::write(&workspace_config_path, content)
-            .await
-            .with_context(|| context.clone())?;
-
-        info!(
-            "Workspace configuration saved to: {:?}",
-            workspace_config_path
-        );
-        Ok(())
+        write_workspace_config(&workspace_config_path, config).await
     }
 
     /// Create a default global configuration file
@@ -180,11 +195,8 @@ impl ConfigLoader {
             default_config.metadata.name = Some(name);
         }
 
-        // Set creation timestamp
-        default_config.metadata.created_at = Some(chrono::Utc::now());
-        default_config.metadata.last_modified = Some(chrono::Utc::now());
-
-        self.save_workspace_config(workspace_path, &default_config)
+        // Saving sets the creation and modification timestamps
+        self.save_workspace_config(workspace_path, &mut default_config)
             .await
             .with_context(|| context)?;
 
@@ -366,41 +378,118 @@ impl ConfigLoader {
         global: &GlobalConfig,
         workspace: &WorkspaceConfig,
     ) -> GlobalConfig {
-        let mut merged = global.clone();
+        workspace.merge_over_global(global)
+    }
+}
 
-        // Merge UI configuration
-        if let Some(ui) = &workspace.ui {
-            merged.ui = ui.clone();
-        }
+/// Validate, touch, and write a workspace configuration file
+///
+/// The metadata timestamps are updated on a copy, which replaces `config`
+/// only once the file is written, so a failed save leaves it unchanged.
+pub(crate) async fn write_workspace_config(
+    path: &Path,
+    config: &mut WorkspaceConfig,
+) -> Result<()> {
+    let context = ErrorContext::new().with_context("Workspace configuration saving");
+
+    config.validate().with_context(|| context.clone())?;
+
+    let mut touched = config.clone();
+    touched.touch();
+    let content = toml::to_string_pretty(&touched)
+        .with_context_msg("Failed to serialize workspace configuration to TOML")?;
+
+    write_atomic(path, &content)
+        .await
+        .with_context(|| context.clone())?;
+    *config = touched;
+
+    info!("Workspace configuration saved to: {:?}", path);
+    Ok(())
+}
 
-        // Merge session configuration
-        if let Some(session) = &workspace.session {
-            merged.session = session.clone();
-        }
+/// Replace a configuration file without leaving it half-written
+///
+/// The content goes to a temporary file in the same directory, which is then
+/// renamed over the target, so a crash leaves either the old or the new file.
+/// An existing file's permissions carry over to the replacement. A symlinked
+/// path, as with managed dotfiles, is resolved first so the link's target is
+/// replaced rather than the link itself.
+pub(crate) async fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let resolved = tokio::fs::canonicalize(path).await.ok();
+    let path = resolved.as_deref().unwrap_or(path);
+    let temp_path = write_temp(path, content).await?;
+
+    if let Err(e) = tokio::fs::rename(&temp_path, path).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e.into());
+    }
 
-        // Merge LLM configuration
-        if let Some(llm) = &workspace.llm {
-            merged.llm = llm.clone();
-        }
+    // The rename is only durable once the directory entry is flushed
+    #[cfg(unix)]
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        tokio::fs::File::open(parent).await?.sync_all().await?;
+    }
 
-        // Merge database configuration
-        if let Some(database) = &workspace.database {
-            merged.database = database.clone();
-        }
+    Ok(())
+}
 
-        // Merge shortcuts configuration
-        if let Some(shortcuts) = &workspace.shortcuts {
-            merged.shortcuts = shortcuts.clone();
+/// Write and flush the temporary file that `write_atomic` renames into place
+///
+/// Each call gets its own file name, so concurrent saves of the same target
+/// never write to the same temporary file. On Unix the file is created with
+/// the target's mode, so its content is never readable more widely.
+async fn write_temp(path: &Path, content: &str) -> Result<PathBuf> {
+    static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let permissions = tokio::fs::metadata(path)
+        .await
+        .ok()
+        .map(|metadata| metadata.permissions());
+
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    if let Some(permissions) = &permissions {
+        use std::os::unix::fs::PermissionsExt;
+        options.mode(permissions.mode());
+    }
+    let mut file = options.open(&temp_path).await?;
+
+    let result = async {
+        file.write_all(content.as_bytes()).await?;
+
+        // The umask may have narrowed the mode given at creation
+        if let Some(permissions) = permissions {
+            file.set_permissions(permissions).await?;
         }
 
-        merged
+        file.sync_all().await
+    }
+    .await;
+
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e.into());
     }
+
+    Ok(temp_path)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::path::PathBuf;
+    use crate::config::UiOverrides;
     use tempfile::TempDir;
 
     #[tokio::test]
@@ -447,9 +536,9 @@ mod tests {
         assert!(config.is_none());
 
         // Create a workspace config
-        let test_config = WorkspaceConfig::default();
+        let mut test_config = WorkspaceConfig::default();
         loader
-            .save_workspace_config(workspace_path, &test_config)
+            .save_workspace_config(workspace_path, &mut test_config)
             .await
             .unwrap();
 
@@ -458,6 +547,169 @@ mod tests {
         assert!(config.is_some());
     }
 
+    #[tokio::test]
+    async fn test_load_workspace_config_expands_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join(".pixlie-workspace.toml"),
+            "[metadata]\ndefault_database = \"${DATA_DIR}/main.db\"\n\n\
+             [metadata.environment]\nDATA_DIR = \"/srv/data\"\n",
+        )
+        .unwrap();
+        let loader = ConfigLoader::new().unwrap();
+
+        let loaded = loader.load_workspace_config(temp_dir.path()).await.unwrap();
+        assert_eq!(
+            loaded.unwrap().metadata.default_database.as_deref(),
+            Some("/srv/data/main.db")
+        );
+
+        // Reading for a rewrite keeps the reference
+        let raw = loader.read_workspace_config(temp_dir.path()).await.unwrap();
+        assert_eq!(
+            raw.unwrap().metadata.default_database.as_deref(),
+            Some("${DATA_DIR}/main.db")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_workspace_config_touches_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_path = temp_dir.path();
+        let loader = ConfigLoader::new().unwrap();
+
+        let mut config = WorkspaceConfig::default();
+        config.metadata.created_at = None;
+        config.metadata.last_modified = None;
+        loader
+            .save_workspace_config(workspace_path, &mut config)
+            .await
+            .unwrap();
+        let first = loader
+            .load_workspace_config(workspace_path)
+            .await
+            .unwrap()
+            .unwrap();
+        let created_at = first.metadata.created_at.unwrap();
+        let first_modified = first.metadata.last_modified.unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let mut reloaded = first.clone();
+        loader
+            .save_workspace_config(workspace_path, &mut reloaded)
+            .await
+            .unwrap();
+        let second = loader
+            .load_workspace_config(workspace_path)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(second.metadata.created_at, Some(created_at));
+        assert!(second.metadata.last_modified.unwrap() > first_modified);
+    }
+
+    #[tokio::test]
+    async fn test_failed_workspace_save_keeps_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let blocker = temp_dir.path().join("not-a-directory");
+        std::fs::write(&blocker, "").unwrap();
+
+        let mut config = WorkspaceConfig::default();
+        let last_modified = config.metadata.last_modified;
+        let loader = ConfigLoader::new().unwrap();
+        assert!(loader
+            .save_workspace_config(&blocker, &mut config)
+            .await
+            .is_err());
+        assert_eq!(config.metadata.last_modified, last_modified);
+    }
+
+    #[tokio::test]
+    async fn test_write_atomic_leaves_original_until_rename() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("config.toml");
+        std::fs::write(&config_file, "[ui]\ntheme = \"dark\"\n").unwrap();
+
+        // A crash after the temp write must leave the original intact
+        let temp_path = write_temp(&config_file, "[ui]\ntheme = \"li")
+            .await
+            .unwrap();
+        assert_eq!(temp_path.parent(), config_file.parent());
+        assert_eq!(
+            std::fs::read_to_string(&config_file).unwrap(),
+            "[ui]\ntheme = \"dark\"\n"
+        );
+        std::fs::remove_file(&temp_path).unwrap();
+
+        write_atomic(&config_file, "[ui]\ntheme = \"light\"\n")
+            .await
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&config_file).unwrap(),
+            "[ui]\ntheme = \"light\"\n"
+        );
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_atomic_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("config.toml");
+        std::fs::write(&config_file, "").unwrap();
+        std::fs::set_permissions(&config_file, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+        write_atomic(&config_file, "[ui]\n").await.unwrap();
+
+        let mode = std::fs::metadata(&config_file)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_atomic_follows_symlinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let dotfiles = temp_dir.path().join("dotfiles");
+        std::fs::create_dir(&dotfiles).unwrap();
+        let target = dotfiles.join("config.toml");
+        std::fs::write(&target, "").unwrap();
+        let link = temp_dir.path().join("config.toml");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        write_atomic(&link, "[ui]\n").await.unwrap();
+
+        assert!(std::fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "[ui]\n");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_temp_uses_target_mode_and_unique_names() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_file = temp_dir.path().join("config.toml");
+        std::fs::write(&config_file, "").unwrap();
+        std::fs::set_permissions(&config_file, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+        let first = write_temp(&config_file, "[ui]\n").await.unwrap();
+        let second = write_temp(&config_file, "[ui]\n").await.unwrap();
+        assert_ne!(first, second);
+
+        let mode = std::fs::metadata(&first).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
     #[test]
     fn test_apply_environment_overrides() {
         let loader = ConfigLoader::new().unwrap();
@@ -484,16 +736,20 @@ mod tests {
     fn test_merge_workspace_into_global() {
         let loader = ConfigLoader::new().unwrap();
         let global = GlobalConfig::default();
-        let mut workspace = WorkspaceConfig::default();
 
         // Set some workspace overrides
-        let mut ui_override = global.ui.clone();
-        ui_override.theme = "light".to_string();
-        workspace.ui = Some(ui_override);
+        let workspace = WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("light".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
 
         let merged = loader.merge_workspace_into_global(&global, &workspace);
 
         assert_eq!(merged.ui.theme, "light");
+        assert_eq!(merged.ui.layout, global.ui.layout); // Inherited from global
         assert_eq!(merged.llm.default_model, global.llm.default_model); // Should remain unchanged
     }
 }
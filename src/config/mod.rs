@@ -2,21 +2,31 @@
 //!
 //! This module provides comprehensive configuration management including:
 //! - TUI-specific settings (theme, layout, shortcuts)
+//! - Keyboard shortcut parsing
 //! - Workspace-specific configurations
 //! - Database and LLM provider settings
 //! - Configuration file, environment variables, and CLI argument handling
+//! - Provider API key storage in the OS keyring
+//! - Environment variable interpolation in connection strings and paths
 //! - Configuration validation and merging with proper precedence
+//! - Validation reports covering every configuration file
 
+pub mod interpolation;
+pub mod keys;
 pub mod loader;
+pub mod report;
+pub mod secrets;
 pub mod settings;
 pub mod workspace;
 
 pub use loader::*;
+pub use report::ValidationReport;
 pub use settings::*;
 pub use workspace::*;
 
 use crate::error::{ErrorContext, ErrorContextExt, PixlieError, Result};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
 
 /// Trait representing CLI arguments needed by the configuration system
 pub trait CliArgs {
@@ -93,16 +103,21 @@ impl ConfigManager {
         // Validate global configuration
         self.global.validate().with_context(|| context.clone())?;
 
-        // Validate workspace configuration if present
+        // Validate workspace configuration and the merged result if present
         if let Some(workspace) = &self.workspace {
             workspace.validate().with_context(|| context.clone())?;
+            self.effective_config()
+                .validate()
+                .with_context(|| context.clone())?;
         }
 
         Ok(())
     }
 
     /// Save current configuration to files
-    pub async fn save(&self) -> Result<()> {
+    ///
+    /// The workspace's last modified time is updated as part of the save.
+    pub async fn save(&mut self) -> Result<()> {
         let context = ErrorContext::new().with_context("Configuration saving");
 
         // Save global configuration
@@ -111,8 +126,8 @@ impl ConfigManager {
             .with_context(|| context.clone())?;
 
         // Save workspace configuration if present
-        if let Some(workspace) = &self.workspace {
-            self.save_workspace_config(workspace)
+        if let (Some(workspace), Some(path)) = (&mut self.workspace, &self.paths.workspace_config) {
+            loader::write_workspace_config(path, workspace)
                 .await
                 .with_context(|| context.clone())?;
         }
@@ -120,55 +135,137 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Configuration as stored on disk, without environment or CLI overrides
+    ///
+    /// When the workspace file doesn't exist yet, the in-memory workspace is
+    /// used so that saving creates it.
+    pub async fn load_persisted(&self) -> Result<Self> {
+        let loader = ConfigLoader::with_paths(self.paths.clone());
+        let global = loader.load_global_config().await?;
+
+        let workspace_dir = self
+            .paths
+            .workspace_config
+            .as_deref()
+            .and_then(Path::parent);
+        let workspace = match workspace_dir {
+            Some(dir) => loader
+                .read_workspace_config(dir)
+                .await?
+                .or_else(|| self.workspace.clone()),
+            None => self.workspace.clone(),
+        };
+
+        Ok(Self {
+            global,
+            workspace,
+            paths: self.paths.clone(),
+        })
+    }
+
+    /// Effective configuration for the current context
+    ///
+    /// Workspace values take precedence field by field; anything the workspace
+    /// leaves unset falls back to the global configuration.
+    pub fn effective_config(&self) -> GlobalConfig {
+        match &self.workspace {
+            Some(workspace) => workspace.merge_over_global(&self.global),
+            None => self.global.clone(),
+        }
+    }
+
     /// Get the effective UI configuration for the current context
-    pub fn effective_ui_config(&self) -> &UiConfig {
-        // Workspace UI settings override global ones
-        if let Some(workspace) = &self.workspace {
-            if let Some(ui) = &workspace.ui {
-                return ui;
-            }
+    ///
+    /// Borrows the global section when the workspace doesn't override it.
+    pub fn effective_ui_config(&self) -> Cow<'_, UiConfig> {
+        match self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.ui.as_ref())
+        {
+            Some(overrides) => Cow::Owned(overrides.merge_over(&self.global.ui)),
+            None => Cow::Borrowed(&self.global.ui),
         }
-        &self.global.ui
     }
 
     /// Get the effective session configuration for the current context
-    pub fn effective_session_config(&self) -> &SessionConfig {
-        if let Some(workspace) = &self.workspace {
-            if let Some(session) = &workspace.session {
-                return session;
-            }
+    ///
+    /// Borrows the global section when the workspace doesn't override it.
+    pub fn effective_session_config(&self) -> Cow<'_, SessionConfig> {
+        match self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.session.as_ref())
+        {
+            Some(overrides) => Cow::Owned(overrides.merge_over(&self.global.session)),
+            None => Cow::Borrowed(&self.global.session),
         }
-        &self.global.session
     }
 
     /// Get the effective LLM configuration for the current context
-    pub fn effective_llm_config(&self) -> &LlmConfig {
-        if let Some(workspace) = &self.workspace {
-            if let Some(llm) = &workspace.llm {
-                return llm;
-            }
+    ///
+    /// Borrows the global section when the workspace doesn't override it.
+    pub fn effective_llm_config(&self) -> Cow<'_, LlmConfig> {
+        match self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.llm.as_ref())
+        {
+            Some(overrides) => Cow::Owned(overrides.merge_over(&self.global.llm)),
+            None => Cow::Borrowed(&self.global.llm),
         }
-        &self.global.llm
     }
 
     /// Get the effective database configuration for the current context
-    pub fn effective_database_config(&self) -> &DatabaseConfig {
-        if let Some(workspace) = &self.workspace {
-            if let Some(database) = &workspace.database {
-                return database;
-            }
+    ///
+    /// Borrows the global section when the workspace doesn't override it.
+    pub fn effective_database_config(&self) -> Cow<'_, DatabaseConfig> {
+        match self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.database.as_ref())
+        {
+            Some(overrides) => Cow::Owned(overrides.merge_over(&self.global.database)),
+            None => Cow::Borrowed(&self.global.database),
         }
-        &self.global.database
     }
 
     /// Get the effective shortcuts configuration for the current context
-    pub fn effective_shortcuts_config(&self) -> &ShortcutsConfig {
-        if let Some(workspace) = &self.workspace {
-            if let Some(shortcuts) = &workspace.shortcuts {
-                return shortcuts;
-            }
+    ///
+    /// Borrows the global section when the workspace doesn't override it.
+    pub fn effective_shortcuts_config(&self) -> Cow<'_, ShortcutsConfig> {
+        match self
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.shortcuts.as_ref())
+        {
+            Some(overrides) => Cow::Owned(overrides.merge_over(&self.global.shortcuts)),
+            None => Cow::Borrowed(&self.global.shortcuts),
         }
-        &self.global.shortcuts
+    }
+
+    /// Resolve the database path for the current workspace
+    ///
+    /// Falls back to `fallback` when no workspace is loaded or the workspace
+    /// doesn't configure a database. The parent directory is created so the
+    /// database can be opened directly.
+    pub async fn resolve_database_path(&self, fallback: &Path) -> Result<PathBuf> {
+        let workspace_root = self
+            .paths
+            .workspace_config
+            .as_deref()
+            .and_then(Path::parent);
+
+        let path = match (&self.workspace, workspace_root) {
+            (Some(workspace), Some(root)) => workspace.resolve_database_path(root, fallback)?,
+            _ => fallback.to_path_buf(),
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        Ok(path)
     }
 
     /// Load environment variables
@@ -208,7 +305,8 @@ impl ConfigManager {
 
         if workspace_config_path.exists() {
             let content = tokio::fs::read_to_string(&workspace_config_path).await?;
-            let config: WorkspaceConfig = toml::from_str(&content)?;
+            let mut config: WorkspaceConfig = toml::from_str(&content)?;
+            config.expand_variables(|name| std::env::var(name).ok())?;
             self.workspace = Some(config);
             self.paths.workspace_config = Some(workspace_config_path);
         }
@@ -239,25 +337,9 @@ impl ConfigManager {
 
     /// Save global configuration to file
     async fn save_global_config(&self) -> Result<()> {
-        // Ensure config directory exists
-        if let Some(parent) = self.paths.global_config.parent() {
-            tokio::fs::create_dir_all(parent).await?;
-        }
-
-        let content = toml::to_string_pretty(&self.global)?;
-        tokio::fs::write(&self.paths.global_config, content).await?;
-
-        Ok(())
-    }
-
-    /// Save workspace configuration to file
-    async fn save_workspace_config(&self, workspace: &WorkspaceConfig) -> Result<()> {
-        if let Some(workspace_config_path) = &self.paths.workspace_config {
-            let content = toml::to_string_pretty(workspace)?;
-            tokio::fs::write(workspace_config_path, content).await?;
-        }
-
-        Ok(())
+        ConfigLoader::with_paths(self.paths.clone())
+            .save_global_config(&self.global)
+            .await
     }
 }
 
@@ -286,7 +368,6 @@ impl ConfigPaths {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tempfile::TempDir;
 
     #[tokio::test]
     async fn test_config_manager_creation() {
@@ -307,4 +388,62 @@ mod tests {
         assert!(paths.global_config.to_string_lossy().contains("pixlie"));
         assert!(paths.config_dir.to_string_lossy().contains("pixlie"));
     }
+
+    #[tokio::test]
+    async fn test_resolve_database_path_creates_parent() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let workspace_root = temp_dir.path().join("sales");
+
+        let mut manager = ConfigManager::new().unwrap();
+        let mut workspace = WorkspaceConfig::default();
+        workspace.metadata.default_database = Some("db/main.db".to_string());
+        manager.workspace = Some(workspace);
+        manager.paths.workspace_config = Some(workspace_root.join(".pixlie-workspace.toml"));
+
+        let fallback = temp_dir.path().join("global").join("pixlie.db");
+        let path = manager.resolve_database_path(&fallback).await.unwrap();
+
+        assert_eq!(path, workspace_root.join("db").join("main.db"));
+        assert!(workspace_root.join("db").is_dir());
+    }
+
+    #[test]
+    fn test_validate_workspace_theme_against_global_themes() {
+        let mut manager = ConfigManager::new().unwrap();
+        manager.workspace = Some(WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("ocean".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert!(manager.validate().is_err());
+
+        manager
+            .global
+            .ui
+            .themes
+            .insert("ocean".to_string(), ThemeConfig::default());
+        assert!(manager.validate().is_ok());
+    }
+
+    #[test]
+    fn test_effective_config_workspace_overrides_theme_only() {
+        let mut manager = ConfigManager::new().unwrap();
+        manager.global.ui.layout = "spacious".to_string();
+        manager.global.llm.temperature = 0.2;
+        manager.workspace = Some(WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("light".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let ui = manager.effective_ui_config();
+        assert_eq!(ui.theme, "light");
+        assert_eq!(ui.layout, "spacious");
+        assert_eq!(manager.effective_llm_config().temperature, 0.2);
+        assert_eq!(manager.global.ui.theme, "dark");
+    }
 }
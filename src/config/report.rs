@@ -0,0 +1,332 @@
+//! Configuration diagnostics for the `validate` command
+//!
+//! Unlike [`ConfigLoader`], which stops at the first problem, the report
+//! checks every configuration file independently. Validation stops at the
+//! first invalid value, so each file, the environment overrides, and the
+//! merged configuration report at most one validation error each, together
+//! with every key the configuration types don't recognize.
+
+use super::{ConfigLoader, GlobalConfig, WorkspaceConfig};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Result of checking the global and workspace configuration files
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    /// Per-file results, global configuration first
+    pub files: Vec<FileReport>,
+
+    /// First error introduced by `PIXLIE_*` environment overrides
+    pub environment_errors: Vec<String>,
+
+    /// First error in the workspace configuration merged over the global one
+    pub merged_errors: Vec<String>,
+}
+
+/// Result of checking a single configuration file
+#[derive(Debug)]
+pub struct FileReport {
+    /// Path of the configuration file
+    pub path: PathBuf,
+
+    /// Whether the file exists; missing files fall back to defaults
+    pub found: bool,
+
+    /// Read or parse error, or else the first validation error
+    pub errors: Vec<String>,
+
+    /// Dotted paths of keys that are not part of the configuration format
+    pub unknown_keys: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Check the global configuration and, if given, a workspace directory
+    pub async fn check(loader: &ConfigLoader, workspace: Option<&Path>) -> Self {
+        let mut report = Self::default();
+
+        let (mut global_report, global) =
+            check_file::<GlobalConfig>(&loader.paths().global_config).await;
+        let global = global.unwrap_or_default();
+        if global_report.errors.is_empty() {
+            if let Err(e) = global.validate() {
+                global_report.errors.push(e.user_message());
+            }
+        }
+        let global_valid = global_report.errors.is_empty();
+        report.files.push(global_report);
+
+        // The application overlays environment variables on the global
+        // configuration, and the workspace is merged over that result
+        let mut runtime_global = global.clone();
+        if global_valid {
+            let overlaid = loader
+                .apply_environment_overrides(&mut runtime_global)
+                .and_then(|_| runtime_global.validate());
+            if let Err(e) = overlaid {
+                report.environment_errors.push(e.user_message());
+            }
+        }
+        let runtime_valid = global_valid && report.environment_errors.is_empty();
+
+        if let Some(workspace_dir) = workspace {
+            let path = workspace_dir.join(".pixlie-workspace.toml");
+            let (mut workspace_report, workspace) = check_file::<WorkspaceConfig>(&path).await;
+
+            if let Some(mut workspace) = workspace {
+                let expanded = workspace
+                    .expand_variables(|name| std::env::var(name).ok())
+                    .and_then(|_| workspace.validate());
+                if let Err(e) = expanded {
+                    workspace_report.errors.push(e.user_message());
+                } else if runtime_valid {
+                    if let Err(e) = workspace.merge_over_global(&runtime_global).validate() {
+                        report.merged_errors.push(e.user_message());
+                    }
+                }
+            }
+            report.files.push(workspace_report);
+        }
+
+        report
+    }
+
+    /// Whether no errors were found; unknown keys are only warnings
+    pub fn is_ok(&self) -> bool {
+        self.environment_errors.is_empty()
+            && self.merged_errors.is_empty()
+            && self.files.iter().all(|file| file.errors.is_empty())
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for file in &self.files {
+            let status = if !file.found {
+                "not found, using defaults"
+            } else if file.errors.is_empty() {
+                "ok"
+            } else {
+                "invalid"
+            };
+            writeln!(f, "{}: {}", file.path.display(), status)?;
+
+            for error in &file.errors {
+                writeln!(f, "  error: {}", error)?;
+            }
+            for key in &file.unknown_keys {
+                writeln!(f, "  warning: unknown key '{}'", key)?;
+            }
+        }
+
+        if !self.environment_errors.is_empty() {
+            writeln!(f, "environment overrides: invalid")?;
+            for error in &self.environment_errors {
+                writeln!(f, "  error: {}", error)?;
+            }
+        }
+
+        if !self.merged_errors.is_empty() {
+            writeln!(f, "workspace merged over global: invalid")?;
+            for error in &self.merged_errors {
+                writeln!(f, "  error: {}", error)?;
+            }
+        }
+
+        if self.is_ok() {
+            write!(f, "Configuration is valid")
+        } else {
+            write!(f, "Configuration has errors")
+        }
+    }
+}
+
+/// Read and parse a configuration file, recording problems in its report
+async fn check_file<T>(path: &Path) -> (FileReport, Option<T>)
+where
+    T: DeserializeOwned + Serialize,
+{
+    let mut report = FileReport {
+        path: path.to_path_buf(),
+        found: path.exists(),
+        errors: Vec::new(),
+        unknown_keys: Vec::new(),
+    };
+
+    if !report.found {
+        return (report, None);
+    }
+
+    let content = match tokio::fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) => {
+            report.errors.push(format!("Failed to read file: {}", e));
+            return (report, None);
+        }
+    };
+
+    match toml::from_str::<T>(&content) {
+        Ok(config) => {
+            report.unknown_keys = unknown_keys(&content, &config);
+            (report, Some(config))
+        }
+        Err(e) => {
+            report.errors.push(format!("Failed to parse TOML: {}", e));
+            (report, None)
+        }
+    }
+}
+
+/// Keys present in `content` that are dropped when parsed into `config`
+///
+/// Serde ignores fields it doesn't know, so a key is unknown when it is
+/// missing from the parsed configuration serialized back to TOML. Empty
+/// tables and arrays hold no values and are skipped when serializing maps,
+/// so they are never reported.
+pub fn unknown_keys<T: Serialize>(content: &str, config: &T) -> Vec<String> {
+    let (Ok(raw), Ok(parsed)) = (
+        content.parse::<toml::Table>(),
+        toml::Table::try_from(config),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut keys = Vec::new();
+    diff_tables("", &raw, &parsed, &mut keys);
+    keys
+}
+
+fn diff_tables(prefix: &str, raw: &toml::Table, parsed: &toml::Table, keys: &mut Vec<String>) {
+    for (key, raw_value) in raw {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        match parsed.get(key) {
+            None if is_empty(raw_value) => {}
+            None => keys.push(path),
+            Some(parsed_value) => diff_values(&path, raw_value, parsed_value, keys),
+        }
+    }
+}
+
+fn is_empty(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::Table(table) => table.is_empty(),
+        toml::Value::Array(array) => array.is_empty(),
+        _ => false,
+    }
+}
+
+fn diff_values(path: &str, raw: &toml::Value, parsed: &toml::Value, keys: &mut Vec<String>) {
+    match (raw, parsed) {
+        (toml::Value::Table(raw), toml::Value::Table(parsed)) => {
+            diff_tables(path, raw, parsed, keys)
+        }
+        (toml::Value::Array(raw), toml::Value::Array(parsed)) => {
+            for (i, (raw, parsed)) in raw.iter().zip(parsed).enumerate() {
+                diff_values(&format!("{}[{}]", path, i), raw, parsed, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigPaths;
+
+    #[test]
+    fn test_unknown_keys_reports_dotted_paths() {
+        let content = r#"
+            typo_section = true
+
+            [ui]
+            theme = "light"
+            colour = true
+
+            [llm.providers.openai]
+            endpoint = "https://api.openai.com/v1"
+            api_key = "inline"
+        "#;
+        let config: GlobalConfig = toml::from_str(content).unwrap();
+
+        let mut keys = unknown_keys(content, &config);
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec!["llm.providers.openai.api_key", "typo_section", "ui.colour"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_ignores_empty_workspace_themes() {
+        let content = r#"
+            [ui]
+            theme = "light"
+
+            [ui.themes]
+        "#;
+        let config: WorkspaceConfig = toml::from_str(content).unwrap();
+
+        assert!(unknown_keys(content, &config).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_keys_accepts_known_configuration() {
+        let content = toml::to_string_pretty(&GlobalConfig::default()).unwrap();
+        let config: GlobalConfig = toml::from_str(&content).unwrap();
+
+        assert!(unknown_keys(&content, &config).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_file_collects_parse_errors() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        std::fs::write(&path, "[ui\ntheme = ").unwrap();
+
+        let (report, config) = check_file::<GlobalConfig>(&path).await;
+        assert!(report.found);
+        assert!(config.is_none());
+        assert_eq!(report.errors.len(), 1);
+
+        let (missing, _) = check_file::<GlobalConfig>(&temp_dir.path().join("none.toml")).await;
+        assert!(!missing.found);
+        assert!(missing.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_accepts_workspace_theme_defined_globally() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let global_config = temp_dir.path().join("config.toml");
+        std::fs::write(&global_config, "[ui.themes.ocean]\naccent = \"cyan\"\n").unwrap();
+
+        let workspace_dir = temp_dir.path().join("sales");
+        std::fs::create_dir(&workspace_dir).unwrap();
+        std::fs::write(
+            workspace_dir.join(".pixlie-workspace.toml"),
+            "[ui]\ntheme = \"ocean\"\n",
+        )
+        .unwrap();
+
+        let loader = ConfigLoader::with_paths(ConfigPaths {
+            global_config,
+            config_dir: temp_dir.path().to_path_buf(),
+            workspace_config: None,
+        });
+
+        let report = ValidationReport::check(&loader, Some(&workspace_dir)).await;
+        assert!(report.is_ok(), "{}", report);
+
+        // The same workspace fails once the global theme is gone
+        std::fs::write(temp_dir.path().join("config.toml"), "").unwrap();
+        let report = ValidationReport::check(&loader, Some(&workspace_dir)).await;
+        assert!(!report.is_ok());
+        assert_eq!(report.merged_errors.len(), 1);
+    }
+}
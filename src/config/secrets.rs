@@ -0,0 +1,294 @@
+//! Secure API key storage for Pixlie TUI application
+//!
+//! Provider API keys are stored in the OS keyring, keyed by workspace and
+//! provider name, so they never need to be written to TOML files. When no
+//! keyring backend is available (e.g. headless CI), key resolution falls back
+//! to the provider's `api_key_env` environment variable.
+
+use super::ProviderConfig;
+use crate::error::{ErrorContext, PixlieError, Result};
+use tracing::{debug, warn};
+
+/// Keyring service name under which all Pixlie secrets are stored
+pub const KEYRING_SERVICE: &str = "pixlie";
+
+/// Workspace scope used for keys that are not tied to a workspace
+const GLOBAL_SCOPE: &str = "global";
+
+/// Build the keyring entry name for a workspace and provider
+pub fn keyring_entry_name(workspace: Option<&str>, provider: &str) -> String {
+    format!("{}/{}", workspace.unwrap_or(GLOBAL_SCOPE), provider)
+}
+
+/// Keyring entry name used for a provider's API key
+///
+/// A provider's `keyring_entry` takes precedence over the default
+/// `<workspace>/<provider>` entry.
+pub fn provider_entry_name(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+) -> String {
+    config
+        .keyring_entry
+        .clone()
+        .unwrap_or_else(|| keyring_entry_name(workspace, provider))
+}
+
+/// Store a provider API key in the OS keyring
+pub fn set_api_key(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+    api_key: &str,
+) -> Result<()> {
+    let entry_name = provider_entry_name(workspace, provider, config);
+    let entry = open_entry(&entry_name)?;
+
+    entry
+        .set_password(api_key)
+        .map_err(|e| keyring_error("Failed to store API key", &entry_name, e))?;
+
+    debug!(entry = entry_name, "Stored API key in keyring");
+    Ok(())
+}
+
+/// Retrieve a provider API key from the OS keyring
+///
+/// Returns `Ok(None)` when no key has been stored for this provider.
+pub fn get_api_key(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+) -> Result<Option<String>> {
+    get_entry_password(&provider_entry_name(workspace, provider, config))
+}
+
+/// Remove a provider API key from the OS keyring
+///
+/// Returns `Ok(false)` when there was no key to remove.
+pub fn delete_api_key(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+) -> Result<bool> {
+    let entry_name = provider_entry_name(workspace, provider, config);
+    let entry = open_entry(&entry_name)?;
+
+    match entry.delete_credential() {
+        Ok(()) => {
+            debug!(entry = entry_name, "Deleted API key from keyring");
+            Ok(true)
+        }
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(keyring_error("Failed to delete API key", &entry_name, e)),
+    }
+}
+
+/// Resolve the API key for a provider
+///
+/// The provider's keyring entry (see [`provider_entry_name`]) is consulted
+/// first. If it is missing or the keyring is unavailable, the environment
+/// variable named by `api_key_env` is used.
+pub fn resolve_api_key(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+) -> Option<String> {
+    resolve_api_key_with(workspace, provider, config, |var| std::env::var(var).ok())
+}
+
+/// Resolve the API key for a provider, reading variables through `env`
+fn resolve_api_key_with(
+    workspace: Option<&str>,
+    provider: &str,
+    config: &ProviderConfig,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    match get_api_key(workspace, provider, config) {
+        Ok(Some(api_key)) => return Some(api_key),
+        Ok(None) => debug!(provider = provider, "No API key in keyring"),
+        Err(e) => warn!(
+            provider = provider,
+            error = %e,
+            "Keyring unavailable, falling back to environment variable"
+        ),
+    }
+
+    config
+        .api_key_env
+        .as_ref()
+        .and_then(|var| env(var))
+        .filter(|api_key| !api_key.is_empty())
+}
+
+fn get_entry_password(entry_name: &str) -> Result<Option<String>> {
+    let entry = open_entry(entry_name)?;
+
+    match entry.get_password() {
+        Ok(api_key) => Ok(Some(api_key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(keyring_error("Failed to read API key", entry_name, e)),
+    }
+}
+
+fn open_entry(entry_name: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, entry_name)
+        .map_err(|e| keyring_error("Failed to open keyring entry", entry_name, e))
+}
+
+fn keyring_error(message: &str, entry_name: &str, err: keyring::Error) -> PixlieError {
+    PixlieError::Configuration {
+        message: format!("{} '{}': {}", message, entry_name, err),
+        context: Box::new(ErrorContext::new().with_context("Keyring access")),
+        source: Some(Box::new(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyring::credential::{Credential, CredentialApi, CredentialBuilderApi};
+    use std::any::Any;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, Once};
+
+    /// In-memory keyring shared by all entries, for hosts without a keyring
+    struct MemoryStore(Arc<Mutex<HashMap<String, Vec<u8>>>>);
+
+    struct MemoryCredential {
+        user: String,
+        secrets: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    }
+
+    impl CredentialApi for MemoryCredential {
+        fn set_secret(&self, secret: &[u8]) -> keyring::Result<()> {
+            let mut secrets = self.secrets.lock().unwrap();
+            secrets.insert(self.user.clone(), secret.to_vec());
+            Ok(())
+        }
+
+        fn get_secret(&self) -> keyring::Result<Vec<u8>> {
+            let secrets = self.secrets.lock().unwrap();
+            secrets
+                .get(&self.user)
+                .cloned()
+                .ok_or(keyring::Error::NoEntry)
+        }
+
+        fn delete_credential(&self) -> keyring::Result<()> {
+            let mut secrets = self.secrets.lock().unwrap();
+            secrets
+                .remove(&self.user)
+                .map(drop)
+                .ok_or(keyring::Error::NoEntry)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    impl CredentialBuilderApi for MemoryStore {
+        fn build(
+            &self,
+            _target: Option<&str>,
+            _service: &str,
+            user: &str,
+        ) -> keyring::Result<Box<Credential>> {
+            Ok(Box::new(MemoryCredential {
+                user: user.to_string(),
+                secrets: self.0.clone(),
+            }))
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// Provider using a fresh keyring entry, backed by the in-memory keyring
+    ///
+    /// The keyring backend is process-wide, so it is installed once before
+    /// any test in this module touches it and never swapped afterwards.
+    fn test_provider() -> ProviderConfig {
+        static MEMORY_KEYRING: Once = Once::new();
+        MEMORY_KEYRING.call_once(|| {
+            keyring::set_default_credential_builder(Box::new(MemoryStore(Default::default())))
+        });
+
+        ProviderConfig {
+            keyring_entry: Some(format!("test/{}", uuid::Uuid::new_v4())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_keyring_entry_name() {
+        assert_eq!(keyring_entry_name(None, "openai"), "global/openai");
+        assert_eq!(
+            keyring_entry_name(Some("analysis"), "anthropic"),
+            "analysis/anthropic"
+        );
+    }
+
+    #[test]
+    fn test_provider_entry_name_prefers_custom_entry() {
+        let mut config = ProviderConfig::default();
+        assert_eq!(
+            provider_entry_name(Some("analysis"), "openai", &config),
+            "analysis/openai"
+        );
+
+        config.keyring_entry = Some("team/openai".to_string());
+        assert_eq!(
+            provider_entry_name(Some("analysis"), "openai", &config),
+            "team/openai"
+        );
+    }
+
+    #[test]
+    fn test_custom_keyring_entry_round_trip() {
+        let mut config = test_provider();
+
+        set_api_key(Some("analysis"), "openai", &config, "sk-custom").unwrap();
+        assert_eq!(
+            resolve_api_key_with(Some("analysis"), "openai", &config, |_| None).as_deref(),
+            Some("sk-custom")
+        );
+
+        // Nothing was written under the default entry name
+        let custom_entry = config.keyring_entry.take();
+        assert!(get_api_key(Some("analysis"), "openai", &config)
+            .unwrap()
+            .is_none());
+
+        config.keyring_entry = custom_entry;
+        assert!(delete_api_key(Some("analysis"), "openai", &config).unwrap());
+        assert!(get_api_key(Some("analysis"), "openai", &config)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_resolve_api_key_falls_back_to_env() {
+        let config = ProviderConfig {
+            api_key_env: Some("OPENAI_API_KEY".to_string()),
+            ..test_provider()
+        };
+
+        let env = |var: &str| (var == "OPENAI_API_KEY").then(|| "sk-test".to_string());
+        let api_key = resolve_api_key_with(None, "openai", &config, env);
+        assert_eq!(api_key.as_deref(), Some("sk-test"));
+
+        // An empty variable counts as unset
+        let api_key = resolve_api_key_with(None, "openai", &config, |_| Some(String::new()));
+        assert!(api_key.is_none());
+    }
+
+    #[test]
+    fn test_resolve_api_key_missing() {
+        let config = test_provider();
+        assert!(resolve_api_key_with(None, "openai", &config, |_| None).is_none());
+    }
+}
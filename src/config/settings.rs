@@ -2,12 +2,14 @@
 //!
 //! Defines all configuration structures for different components of the application.
 
+use super::keys::KeyBinding;
 use crate::error::{ErrorContext, ErrorContextExt, PixlieError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Global application configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GlobalConfig {
     /// UI configuration
     #[serde(default)]
@@ -33,10 +35,14 @@ pub struct GlobalConfig {
 /// TUI interface configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
-    /// Theme name (dark, light, auto)
+    /// Theme name (dark, light, auto, or a custom theme defined in `themes`)
     #[serde(default = "default_theme")]
     pub theme: String,
 
+    /// Custom color themes, keyed by theme name
+    #[serde(default)]
+    pub themes: HashMap<String, ThemeConfig>,
+
     /// Layout style (compact, comfortable, spacious)
     #[serde(default = "default_layout")]
     pub layout: String,
@@ -53,6 +59,10 @@ pub struct UiConfig {
     #[serde(default = "default_log_level")]
     pub log_level: String,
 
+    /// Rotating log file output (disabled when unset)
+    #[serde(default)]
+    pub log_file: Option<LogFileConfig>,
+
     /// Maximum chat history to display in TUI
     #[serde(default = "default_max_chat_history")]
     pub max_chat_history: usize,
@@ -74,6 +84,71 @@ pub struct UiConfig {
     pub animation_duration: u64,
 }
 
+/// Custom color theme definition
+///
+/// Colors accept ratatui color names (`cyan`, `lightblue`, ...), hex values
+/// (`#rrggbb`) or 256-color indices. Unset colors fall back to the dark theme.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Primary text color
+    pub foreground: Option<String>,
+
+    /// Background color
+    pub background: Option<String>,
+
+    /// Borders and field labels
+    pub accent: Option<String>,
+
+    /// Titles and emphasized names
+    pub highlight: Option<String>,
+
+    /// Secondary text and hints
+    pub muted: Option<String>,
+
+    /// Positive status indicators
+    pub success: Option<String>,
+
+    /// Warnings and destructive actions
+    pub error: Option<String>,
+
+    /// Background of selected list items and input fields
+    pub selection: Option<String>,
+}
+
+/// Rotating log file configuration
+///
+/// Log files are written as `<directory>/<file_prefix>.<date>` and rotated on
+/// the configured schedule; the oldest files beyond `max_files` are removed.
+/// With `size` rotation the active file is `<directory>/<file_prefix>` and is
+/// rotated to `<file_prefix>.1`, `<file_prefix>.2`, ... once it reaches
+/// `max_size_mb`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFileConfig {
+    /// Directory log files are written to
+    #[serde(default = "default_log_directory")]
+    pub directory: PathBuf,
+
+    /// File name prefix for log files
+    #[serde(default = "default_log_file_prefix")]
+    pub file_prefix: String,
+
+    /// Rotation policy (minutely, hourly, daily, size, never)
+    #[serde(default = "default_log_rotation")]
+    pub rotation: String,
+
+    /// Log file size in MB that triggers `size` rotation
+    #[serde(default = "default_log_max_size_mb")]
+    pub max_size_mb: u64,
+
+    /// Number of rotated log files to keep
+    #[serde(default = "default_log_max_files")]
+    pub max_files: usize,
+
+    /// Keep logging to the console alongside the file
+    #[serde(default = "default_log_console")]
+    pub console: bool,
+}
+
 /// Session and workspace management configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionConfig {
@@ -100,6 +175,10 @@ pub struct SessionConfig {
     /// Session backup frequency in minutes
     #[serde(default = "default_backup_frequency")]
     pub backup_frequency: u32,
+
+    /// Directory levels below the current and home directories searched for workspaces
+    #[serde(default = "default_workspace_scan_depth")]
+    pub workspace_scan_depth: usize,
 }
 
 /// LLM provider configuration
@@ -139,7 +218,7 @@ pub struct LlmConfig {
 }
 
 /// LLM provider-specific configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProviderConfig {
     /// API endpoint URL
     pub endpoint: Option<String>,
@@ -147,6 +226,9 @@ pub struct ProviderConfig {
     /// API key (should be set via environment variable)
     pub api_key_env: Option<String>,
 
+    /// OS keyring entry holding the API key (defaults to `<workspace>/<provider>`)
+    pub keyring_entry: Option<String>,
+
     /// Custom headers
     #[serde(default)]
     pub headers: HashMap<String, String>,
@@ -229,6 +311,10 @@ pub struct ShortcutsConfig {
     #[serde(default = "default_settings_key")]
     pub settings: String,
 
+    /// Open workspace manager
+    #[serde(default = "default_workspace_manager_key")]
+    pub workspace_manager: String,
+
     /// Send message/execute command
     #[serde(default = "default_send_key")]
     pub send: String,
@@ -254,6 +340,9 @@ pub struct ShortcutsConfig {
     pub nav_right: String,
 }
 
+/// Theme names that are always available without a `themes` entry
+pub const BUILTIN_THEMES: [&str; 3] = ["dark", "light", "auto"];
+
 // Default value functions for serde defaults
 
 fn default_theme() -> String {
@@ -268,6 +357,27 @@ fn default_colored() -> bool {
 fn default_log_level() -> String {
     "info".to_string()
 }
+fn default_log_directory() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("pixlie")
+        .join("logs")
+}
+fn default_log_file_prefix() -> String {
+    "pixlie.log".to_string()
+}
+fn default_log_rotation() -> String {
+    "daily".to_string()
+}
+fn default_log_max_size_mb() -> u64 {
+    10
+}
+fn default_log_max_files() -> usize {
+    7
+}
+fn default_log_console() -> bool {
+    true
+}
 fn default_max_chat_history() -> usize {
     1000
 }
@@ -299,6 +409,9 @@ fn default_auto_save() -> bool {
 fn default_backup_frequency() -> u32 {
     15
 }
+fn default_workspace_scan_depth() -> usize {
+    2
+}
 
 fn default_model() -> String {
     "gpt-3.5-turbo".to_string()
@@ -371,6 +484,9 @@ fn default_prev_objective_key() -> String {
 fn default_settings_key() -> String {
     "Ctrl+,".to_string()
 }
+fn default_workspace_manager_key() -> String {
+    "Ctrl+W".to_string()
+}
 fn default_send_key() -> String {
     "Enter".to_string()
 }
@@ -392,26 +508,16 @@ fn default_nav_right_key() -> String {
 
 // Default implementations
 
-impl Default for GlobalConfig {
-    fn default() -> Self {
-        Self {
-            ui: UiConfig::default(),
-            session: SessionConfig::default(),
-            llm: LlmConfig::default(),
-            database: DatabaseConfig::default(),
-            shortcuts: ShortcutsConfig::default(),
-        }
-    }
-}
-
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             theme: default_theme(),
+            themes: HashMap::new(),
             layout: default_layout(),
             colored: default_colored(),
             json_logs: false,
             log_level: default_log_level(),
+            log_file: None,
             max_chat_history: default_max_chat_history(),
             autosave_interval: default_autosave_interval(),
             show_line_numbers: default_show_line_numbers(),
@@ -430,6 +536,7 @@ impl Default for SessionConfig {
             max_history_size_mb: default_max_history_size_mb(),
             auto_save: default_auto_save(),
             backup_frequency: default_backup_frequency(),
+            workspace_scan_depth: default_workspace_scan_depth(),
         }
     }
 }
@@ -475,6 +582,7 @@ impl Default for ShortcutsConfig {
             next_objective: default_next_objective_key(),
             prev_objective: default_prev_objective_key(),
             settings: default_settings_key(),
+            workspace_manager: default_workspace_manager_key(),
             send: default_send_key(),
             clear_input: default_clear_input_key(),
             nav_up: default_nav_up_key(),
@@ -508,14 +616,39 @@ impl UiConfig {
         let context = ErrorContext::new().with_context("UI configuration validation");
 
         // Validate theme
-        if !["dark", "light", "auto"].contains(&self.theme.as_str()) {
+        if !BUILTIN_THEMES.contains(&self.theme.as_str()) && !self.themes.contains_key(&self.theme)
+        {
             return Err(PixlieError::validation(
                 "ui.theme",
-                "Theme must be 'dark', 'light', or 'auto'",
+                "Theme must be 'dark', 'light', 'auto', or a custom theme defined in ui.themes",
                 context,
             ));
         }
 
+        self.validate_settings()
+    }
+
+    /// Validate every UI setting except whether the theme name resolves
+    ///
+    /// A workspace may select a custom theme defined in the global
+    /// configuration, so the theme reference can only be checked once both
+    /// are merged.
+    pub fn validate_settings(&self) -> Result<()> {
+        let context = ErrorContext::new().with_context("UI configuration validation");
+
+        // Validate custom themes
+        for (name, theme) in &self.themes {
+            if BUILTIN_THEMES.contains(&name.as_str()) {
+                return Err(PixlieError::validation(
+                    format!("ui.themes.{}", name),
+                    format!("Custom theme cannot use the built-in name '{}'", name),
+                    context,
+                ));
+            }
+
+            theme.validate(name).with_context(|| context.clone())?;
+        }
+
         // Validate layout
         if !["compact", "comfortable", "spacious"].contains(&self.layout.as_str()) {
             return Err(PixlieError::validation(
@@ -534,6 +667,10 @@ impl UiConfig {
             ));
         }
 
+        if let Some(log_file) = &self.log_file {
+            log_file.validate().with_context(|| context.clone())?;
+        }
+
         // Validate reasonable values
         if self.max_chat_history > 10000 {
             return Err(PixlieError::validation(
@@ -555,6 +692,107 @@ impl UiConfig {
     }
 }
 
+impl ThemeConfig {
+    /// Validate that every color in the theme parses
+    pub fn validate(&self, name: &str) -> Result<()> {
+        let context = ErrorContext::new().with_context("Theme configuration validation");
+
+        for (field, value) in self.colors() {
+            if let Some(value) = value {
+                if parse_theme_color(value).is_none() {
+                    return Err(PixlieError::configuration(
+                        format!("Invalid color '{}' for ui.themes.{}.{}", value, name, field),
+                        context,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Color fields paired with their names
+    pub fn colors(&self) -> [(&'static str, Option<&String>); 8] {
+        [
+            ("foreground", self.foreground.as_ref()),
+            ("background", self.background.as_ref()),
+            ("accent", self.accent.as_ref()),
+            ("highlight", self.highlight.as_ref()),
+            ("muted", self.muted.as_ref()),
+            ("success", self.success.as_ref()),
+            ("error", self.error.as_ref()),
+            ("selection", self.selection.as_ref()),
+        ]
+    }
+}
+
+impl Default for LogFileConfig {
+    fn default() -> Self {
+        Self {
+            directory: default_log_directory(),
+            file_prefix: default_log_file_prefix(),
+            rotation: default_log_rotation(),
+            max_size_mb: default_log_max_size_mb(),
+            max_files: default_log_max_files(),
+            console: default_log_console(),
+        }
+    }
+}
+
+impl LogFileConfig {
+    /// Validate log file configuration
+    pub fn validate(&self) -> Result<()> {
+        let context = ErrorContext::new().with_context("Log file configuration validation");
+
+        if self.directory.as_os_str().is_empty() {
+            return Err(PixlieError::validation(
+                "ui.log_file.directory",
+                "Log directory cannot be empty",
+                context,
+            ));
+        }
+
+        if self.file_prefix.trim().is_empty() {
+            return Err(PixlieError::validation(
+                "ui.log_file.file_prefix",
+                "Log file prefix cannot be empty",
+                context,
+            ));
+        }
+
+        if !["minutely", "hourly", "daily", "size", "never"].contains(&self.rotation.as_str()) {
+            return Err(PixlieError::validation(
+                "ui.log_file.rotation",
+                "Rotation must be 'minutely', 'hourly', 'daily', 'size', or 'never'",
+                context,
+            ));
+        }
+
+        if self.max_size_mb == 0 {
+            return Err(PixlieError::validation(
+                "ui.log_file.max_size_mb",
+                "Maximum log file size must be at least 1 MB",
+                context,
+            ));
+        }
+
+        if self.max_files == 0 {
+            return Err(PixlieError::validation(
+                "ui.log_file.max_files",
+                "At least one log file must be kept",
+                context,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a theme color string into a ratatui color
+pub fn parse_theme_color(value: &str) -> Option<ratatui::style::Color> {
+    value.trim().parse().ok()
+}
+
 impl SessionConfig {
     /// Validate session configuration
     pub fn validate(&self) -> Result<()> {
@@ -584,6 +822,14 @@ impl SessionConfig {
             ));
         }
 
+        if self.workspace_scan_depth > 5 {
+            return Err(PixlieError::validation(
+                "session.workspace_scan_depth",
+                "Workspace scan depth cannot exceed 5 levels",
+                context,
+            ));
+        }
+
         Ok(())
     }
 }
@@ -673,10 +919,42 @@ impl DatabaseConfig {
 impl ShortcutsConfig {
     /// Validate shortcuts configuration
     pub fn validate(&self) -> Result<()> {
-        // For now, we'll accept any string as a valid shortcut
-        // In a real implementation, we might validate against known key combinations
+        let context = ErrorContext::new().with_context("Shortcuts configuration validation");
+
+        for (name, value) in self.bindings() {
+            if KeyBinding::parse(value).is_none() {
+                return Err(PixlieError::validation(
+                    format!("shortcuts.{}", name),
+                    format!("Unknown key combination '{}'", value),
+                    context,
+                ));
+            }
+        }
+
         Ok(())
     }
+
+    /// Shortcut strings paired with their names
+    pub fn bindings(&self) -> [(&'static str, &String); 16] {
+        [
+            ("quit", &self.quit),
+            ("new_objective", &self.new_objective),
+            ("delete_objective", &self.delete_objective),
+            ("toggle_history", &self.toggle_history),
+            ("save_session", &self.save_session),
+            ("load_session", &self.load_session),
+            ("next_objective", &self.next_objective),
+            ("prev_objective", &self.prev_objective),
+            ("settings", &self.settings),
+            ("workspace_manager", &self.workspace_manager),
+            ("send", &self.send),
+            ("clear_input", &self.clear_input),
+            ("nav_up", &self.nav_up),
+            ("nav_down", &self.nav_down),
+            ("nav_left", &self.nav_left),
+            ("nav_right", &self.nav_right),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -705,6 +983,75 @@ mod tests {
         assert!(ui.validate().is_err());
     }
 
+    #[test]
+    fn test_custom_theme_validation() {
+        let mut ui = UiConfig {
+            theme: "solarized".to_string(),
+            ..Default::default()
+        };
+        assert!(ui.validate().is_err());
+
+        ui.themes.insert(
+            "solarized".to_string(),
+            ThemeConfig {
+                accent: Some("#268bd2".to_string()),
+                highlight: Some("yellow".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(ui.validate().is_ok());
+
+        ui.themes.get_mut("solarized").unwrap().muted = Some("not-a-color".to_string());
+        assert!(matches!(
+            ui.validate(),
+            Err(PixlieError::Configuration { .. })
+        ));
+
+        ui.themes.clear();
+        ui.themes.insert("dark".to_string(), ThemeConfig::default());
+        ui.theme = "dark".to_string();
+        assert!(ui.validate().is_err());
+    }
+
+    #[test]
+    fn test_log_file_validation() {
+        let mut ui = UiConfig {
+            log_file: Some(LogFileConfig::default()),
+            ..Default::default()
+        };
+        assert!(ui.validate().is_ok());
+
+        if let Some(log_file) = ui.log_file.as_mut() {
+            log_file.rotation = "weekly".to_string();
+        }
+        assert!(ui.validate().is_err());
+
+        if let Some(log_file) = ui.log_file.as_mut() {
+            log_file.rotation = "size".to_string();
+            log_file.max_size_mb = 0;
+        }
+        assert!(ui.validate().is_err());
+
+        let log_file: LogFileConfig = toml::from_str("directory = \"/tmp/pixlie\"").unwrap();
+        assert_eq!(log_file.rotation, "daily");
+        assert_eq!(log_file.max_size_mb, 10);
+        assert_eq!(log_file.max_files, 7);
+        assert!(log_file.console);
+    }
+
+    #[test]
+    fn test_shortcuts_validation() {
+        let mut shortcuts = ShortcutsConfig::default();
+        assert!(shortcuts.validate().is_ok());
+
+        shortcuts.quit = "Ctrl+Shift+F5".to_string();
+        assert!(shortcuts.validate().is_ok());
+
+        shortcuts.settings = "Ctrl+Banana".to_string();
+        let err = shortcuts.validate().unwrap_err();
+        assert!(err.to_string().contains("shortcuts.settings"));
+    }
+
     #[test]
     fn test_llm_config_validation() {
         let mut llm = LlmConfig::default();
@@ -3,40 +3,297 @@
 //! Workspace configurations allow per-project customization of settings
 //! that override global defaults when working within specific workspaces.
 
+use super::interpolation::interpolate;
 use super::settings::*;
 use crate::error::{ErrorContext, ErrorContextExt, PixlieError, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
 
 /// Workspace-specific configuration
 ///
 /// This configuration is stored in `.pixlie-workspace.toml` within each workspace
 /// and provides project-specific overrides for global settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Overrides are merged field by field: a value set in the workspace wins, and
+/// anything left unset falls back to the global configuration. Map fields
+/// (`ui.themes`, `llm.providers`) are merged by key.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkspaceConfig {
     /// Workspace metadata
     #[serde(default)]
     pub metadata: WorkspaceMetadata,
 
     /// UI configuration overrides
-    pub ui: Option<UiConfig>,
+    pub ui: Option<UiOverrides>,
 
     /// Session configuration overrides
-    pub session: Option<SessionConfig>,
+    pub session: Option<SessionOverrides>,
 
     /// LLM configuration overrides
-    pub llm: Option<LlmConfig>,
+    pub llm: Option<LlmOverrides>,
 
     /// Database configuration overrides
-    pub database: Option<DatabaseConfig>,
+    pub database: Option<DatabaseOverrides>,
 
     /// Shortcuts configuration overrides
-    pub shortcuts: Option<ShortcutsConfig>,
+    pub shortcuts: Option<ShortcutsOverrides>,
 
     /// Workspace-specific settings
     #[serde(default)]
     pub workspace: WorkspaceSettings,
 }
 
+/// Define a workspace overrides struct for a section of the global configuration
+///
+/// Each field is listed with how it applies over the global value:
+/// - `value`: replaces the global value when set
+/// - `optional`: replaces an optional global value when set
+/// - `map`: merged into the global map by key
+///
+/// The generated `merge_over` destructures and rebuilds the global struct
+/// without `..`, so a field added to the global configuration fails to
+/// compile until it is listed here.
+macro_rules! overrides {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $base:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $kind:ident $ty:ty,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: override_type!($kind $ty),
+            )*
+        }
+
+        impl $name {
+            /// Apply these overrides on top of a base configuration
+            pub fn merge_over(&self, base: &$base) -> $base {
+                let $base { $($field),* } = base.clone();
+                $base {
+                    $($field: merge_field!($kind, self.$field, $field),)*
+                }
+            }
+        }
+    };
+}
+
+/// Type of an override field, see [`overrides!`]
+macro_rules! override_type {
+    (value $ty:ty) => { Option<$ty> };
+    (optional $ty:ty) => { Option<$ty> };
+    (map $ty:ty) => { $ty };
+}
+
+/// Merge one override field over its global value, see [`overrides!`]
+macro_rules! merge_field {
+    (value, $override:expr, $base:expr) => {
+        $override.clone().unwrap_or($base)
+    };
+    (optional, $override:expr, $base:expr) => {
+        $override.clone().or($base)
+    };
+    (map, $override:expr, $base:expr) => {{
+        let mut merged = $base;
+        merged.extend($override.clone());
+        merged
+    }};
+}
+
+overrides! {
+    /// Workspace overrides for the UI configuration
+    ///
+    /// Unset fields inherit the global value.
+    UiOverrides => UiConfig {
+        /// Theme name (dark, light, auto, or a custom theme defined in `themes`)
+        theme: value String,
+
+        /// Custom color themes, keyed by theme name
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        themes: map HashMap<String, ThemeConfig>,
+
+        /// Layout style (compact, comfortable, spacious)
+        layout: value String,
+
+        /// Enable colored output
+        colored: value bool,
+
+        /// JSON logging format
+        json_logs: value bool,
+
+        /// Log level (trace, debug, info, warn, error)
+        log_level: value String,
+
+        /// Rotating log file output (disabled when unset)
+        log_file: optional LogFileConfig,
+
+        /// Maximum chat history to display in TUI
+        max_chat_history: value usize,
+
+        /// Auto-save interval in seconds
+        autosave_interval: value u64,
+
+        /// Enable line numbers in code blocks
+        show_line_numbers: value bool,
+
+        /// Word wrap in chat messages
+        word_wrap: value bool,
+
+        /// Animation duration in milliseconds
+        animation_duration: value u64,
+    }
+}
+
+overrides! {
+    /// Workspace overrides for the session configuration
+    ///
+    /// Unset fields inherit the global value.
+    SessionOverrides => SessionConfig {
+        /// Default workspace directory
+        default_workspace: optional String,
+
+        /// Maximum number of concurrent objectives
+        max_objectives: value usize,
+
+        /// Chat history retention in days
+        history_retention_days: value u32,
+
+        /// Maximum history file size in MB
+        max_history_size_mb: value u64,
+
+        /// Auto-save session state
+        auto_save: value bool,
+
+        /// Session backup frequency in minutes
+        backup_frequency: value u32,
+
+        /// Directory levels below the current and home directories searched for workspaces
+        workspace_scan_depth: value usize,
+    }
+}
+
+overrides! {
+    /// Workspace overrides for the LLM configuration
+    ///
+    /// Unset fields inherit the global value.
+    LlmOverrides => LlmConfig {
+        /// Default model to use
+        default_model: value String,
+
+        /// Maximum iterations for analysis
+        max_iterations: value u32,
+
+        /// Request timeout in seconds
+        request_timeout: value u64,
+
+        /// Maximum tokens per request
+        max_tokens: value u32,
+
+        /// Temperature for response generation
+        temperature: value f32,
+
+        /// Provider-specific configurations
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        providers: map HashMap<String, ProviderConfig>,
+
+        /// Enable streaming responses
+        enable_streaming: value bool,
+
+        /// Retry attempts for failed requests
+        retry_attempts: value u32,
+    }
+}
+
+overrides! {
+    /// Workspace overrides for the database configuration
+    ///
+    /// Unset fields inherit the global value.
+    DatabaseOverrides => DatabaseConfig {
+        /// Connection timeout in seconds
+        connection_timeout: value u64,
+
+        /// Query timeout in seconds
+        query_timeout: value u64,
+
+        /// Maximum number of concurrent connections
+        max_connections: value u32,
+
+        /// Enable read-only mode by default
+        read_only: value bool,
+
+        /// Query result limit
+        query_result_limit: value usize,
+
+        /// Enable query caching
+        enable_caching: value bool,
+
+        /// Cache TTL in seconds
+        cache_ttl: value u64,
+    }
+}
+
+overrides! {
+    /// Workspace overrides for the shortcuts configuration
+    ///
+    /// Unset fields inherit the global value.
+    ShortcutsOverrides => ShortcutsConfig {
+        /// Quit application
+        quit: value String,
+
+        /// Create new objective
+        new_objective: value String,
+
+        /// Delete current objective
+        delete_objective: value String,
+
+        /// Toggle chat history
+        toggle_history: value String,
+
+        /// Save session
+        save_session: value String,
+
+        /// Load session
+        load_session: value String,
+
+        /// Switch to next objective
+        next_objective: value String,
+
+        /// Switch to previous objective
+        prev_objective: value String,
+
+        /// Open settings
+        settings: value String,
+
+        /// Open workspace manager
+        workspace_manager: value String,
+
+        /// Send message/execute command
+        send: value String,
+
+        /// Clear input
+        clear_input: value String,
+
+        /// Navigate up
+        nav_up: value String,
+
+        /// Navigate down
+        nav_down: value String,
+
+        /// Navigate left
+        nav_left: value String,
+
+        /// Navigate right
+        nav_right: value String,
+    }
+}
+
 /// Workspace metadata and identification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceMetadata {
@@ -68,7 +325,7 @@ pub struct WorkspaceMetadata {
 }
 
 /// Workspace-specific settings that don't override global config
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkspaceSettings {
     /// Pinned objectives that persist across sessions
     #[serde(default)]
@@ -304,20 +561,6 @@ fn default_compression_level() -> u8 {
 
 // Default implementations
 
-impl Default for WorkspaceConfig {
-    fn default() -> Self {
-        Self {
-            metadata: WorkspaceMetadata::default(),
-            ui: None,
-            session: None,
-            llm: None,
-            database: None,
-            shortcuts: None,
-            workspace: WorkspaceSettings::default(),
-        }
-    }
-}
-
 impl Default for WorkspaceMetadata {
     fn default() -> Self {
         Self {
@@ -333,20 +576,6 @@ impl Default for WorkspaceMetadata {
     }
 }
 
-impl Default for WorkspaceSettings {
-    fn default() -> Self {
-        Self {
-            pinned_objectives: Vec::new(),
-            query_templates: Vec::new(),
-            workflows: Vec::new(),
-            custom_tools: std::collections::HashMap::new(),
-            data_sources: Vec::new(),
-            auto_load_objectives: false,
-            backup: WorkspaceBackupConfig::default(),
-        }
-    }
-}
-
 impl Default for WorkspaceBackupConfig {
     fn default() -> Self {
         Self {
@@ -366,40 +595,163 @@ impl WorkspaceConfig {
     pub fn validate(&self) -> Result<()> {
         let context = ErrorContext::new().with_context("Workspace configuration validation");
 
-        // Validate UI config override if present
+        // Check the override values over the defaults. Whether the theme name
+        // resolves depends on the global custom themes, so that check is left
+        // to ConfigManager::validate on the merged configuration.
+        let merged = self.merge_over_global(&GlobalConfig::default());
+        merged
+            .ui
+            .validate_settings()
+            .with_context(|| context.clone())?;
+        merged.session.validate().with_context(|| context.clone())?;
+        merged.llm.validate().with_context(|| context.clone())?;
+        merged
+            .database
+            .validate()
+            .with_context(|| context.clone())?;
+        merged
+            .shortcuts
+            .validate()
+            .with_context(|| context.clone())?;
+
+        // Validate workspace-specific settings
+        self.workspace.validate().with_context(|| context)?;
+
+        Ok(())
+    }
+
+    /// Produce the effective configuration of this workspace over a global one
+    pub fn merge_over_global(&self, global: &GlobalConfig) -> GlobalConfig {
+        let mut merged = global.clone();
+
         if let Some(ui) = &self.ui {
-            ui.validate().with_context(|| context.clone())?;
+            merged.ui = ui.merge_over(&global.ui);
         }
 
-        // Validate session config override if present
         if let Some(session) = &self.session {
-            session.validate().with_context(|| context.clone())?;
+            merged.session = session.merge_over(&global.session);
         }
 
-        // Validate LLM config override if present
         if let Some(llm) = &self.llm {
-            llm.validate().with_context(|| context.clone())?;
+            merged.llm = llm.merge_over(&global.llm);
         }
 
-        // Validate database config override if present
         if let Some(database) = &self.database {
-            database.validate().with_context(|| context.clone())?;
+            merged.database = database.merge_over(&global.database);
         }
 
-        // Validate shortcuts config override if present
         if let Some(shortcuts) = &self.shortcuts {
-            shortcuts.validate().with_context(|| context.clone())?;
+            merged.shortcuts = shortcuts.merge_over(&global.shortcuts);
         }
 
-        // Validate workspace-specific settings
-        self.workspace.validate().with_context(|| context)?;
+        merged
+    }
+
+    /// Expand `${VAR}` and `$VAR` references in the workspace's values
+    ///
+    /// The loader calls this once after parsing. Values in the `environment`
+    /// map are expanded from `env`. The default database path and data
+    /// source connection strings then look names up in the expanded map
+    /// first, so the workspace can override process variables.
+    pub fn expand_variables<F>(&mut self, env: F) -> Result<()>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        for (key, value) in self.metadata.environment.iter_mut() {
+            let field = format!("metadata.environment.{}", key);
+            *value = interpolate(&field, value, &env)?;
+        }
+
+        let environment = &self.metadata.environment;
+        let lookup = |name: &str| environment.get(name).cloned().or_else(|| env(name));
+
+        if let Some(path) = &mut self.metadata.default_database {
+            *path = interpolate("metadata.default_database", path, lookup)?;
+        }
+
+        for data_source in &mut self.workspace.data_sources {
+            let field = format!("data_sources.{}.connection", data_source.name);
+            data_source.connection = interpolate(&field, &data_source.connection, lookup)?;
+        }
 
         Ok(())
     }
 
+    /// Resolve the database file this workspace should open
+    ///
+    /// Uses `metadata.default_database` if set, then the first SQLite data
+    /// source, and otherwise `fallback` (the global database). Relative paths
+    /// are resolved against `workspace_root`, and the result, with symlinks
+    /// resolved, must stay inside the workspace root or the fallback's
+    /// directory.
+    pub fn resolve_database_path(&self, workspace_root: &Path, fallback: &Path) -> Result<PathBuf> {
+        let (field, configured) = if let Some(path) = &self.metadata.default_database {
+            ("metadata.default_database".to_string(), path.clone())
+        } else if let Some(data_source) = self
+            .workspace
+            .data_sources
+            .iter()
+            .find(|data_source| data_source.source_type == "sqlite")
+        {
+            // Connection strings may carry options, e.g. `sqlite://app.db?mode=ro`
+            let connection = &data_source.connection;
+            let path = connection.strip_prefix("sqlite://").unwrap_or(connection);
+            let path = path
+                .split_once('?')
+                .map_or(path, |(path, _)| path)
+                .to_string();
+            (
+                format!("data_sources.{}.connection", data_source.name),
+                path,
+            )
+        } else {
+            return Ok(fallback.to_path_buf());
+        };
+
+        let context = ErrorContext::new().with_context("Database path resolution");
+
+        // Relative roots such as "." normalize to an empty path, which every
+        // path starts with, so both roots are made absolute first
+        let root = absolute_root(workspace_root).ok_or_else(|| {
+            PixlieError::validation(
+                field.clone(),
+                format!(
+                    "Cannot resolve workspace directory {}",
+                    workspace_root.display()
+                ),
+                context.clone(),
+            )
+        })?;
+        // Symlinks are resolved so a link inside the workspace can't point
+        // the database somewhere else
+        let path = resolve_symlinks(&normalize_path(&root.join(configured)));
+        let allowed = [Some(root), fallback.parent().and_then(absolute_root)];
+
+        let inside = allowed
+            .iter()
+            .flatten()
+            .any(|root| path.starts_with(resolve_symlinks(root)));
+        if !inside {
+            return Err(PixlieError::validation(
+                field,
+                format!(
+                    "Database path {} is outside the workspace directory",
+                    path.display()
+                ),
+                context,
+            ));
+        }
+
+        Ok(path)
+    }
+
     /// Update the last modified timestamp
+    ///
+    /// The creation timestamp is filled in on the first touch and kept after.
     pub fn touch(&mut self) {
-        self.metadata.last_modified = Some(chrono::Utc::now());
+        let now = chrono::Utc::now();
+        self.metadata.created_at.get_or_insert(now);
+        self.metadata.last_modified = Some(now);
     }
 
     /// Add a pinned objective
@@ -613,6 +965,46 @@ impl WorkspaceBackupConfig {
     }
 }
 
+/// Absolute, normalized form of a directory allowed to contain databases
+///
+/// Returns `None` for paths that can't be made absolute or normalize to
+/// nothing, so they never act as a root that matches every path.
+fn absolute_root(path: &Path) -> Option<PathBuf> {
+    let root = normalize_path(&std::path::absolute(path).ok()?);
+    (!root.as_os_str().is_empty()).then_some(root)
+}
+
+/// Lexically resolve `.` and `..` components without touching the filesystem
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Resolve symlinks in the longest existing prefix of a normalized path
+///
+/// The components that don't exist yet are appended unchanged, so a database
+/// that hasn't been created still resolves.
+fn resolve_symlinks(path: &Path) -> PathBuf {
+    for ancestor in path.ancestors() {
+        if let Ok(resolved) = ancestor.canonicalize() {
+            return match path.strip_prefix(ancestor) {
+                Ok(rest) if !rest.as_os_str().is_empty() => resolved.join(rest),
+                _ => resolved,
+            };
+        }
+    }
+    path.to_path_buf()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -625,6 +1017,229 @@ mod tests {
         assert!(config.workspace.backup.enabled);
     }
 
+    #[test]
+    fn test_ui_override_inherits_global_fields() {
+        let mut global = GlobalConfig::default();
+        global.ui.layout = "compact".to_string();
+        global.ui.max_chat_history = 50;
+
+        let workspace: WorkspaceConfig = toml::from_str("[ui]\ntheme = \"light\"\n").unwrap();
+        let merged = workspace.merge_over_global(&global);
+
+        assert_eq!(merged.ui.theme, "light");
+        assert_eq!(merged.ui.layout, "compact");
+        assert_eq!(merged.ui.max_chat_history, 50);
+        assert_eq!(merged.ui.log_level, global.ui.log_level);
+        assert!(workspace.validate().is_ok());
+    }
+
+    #[test]
+    fn test_map_overrides_merge_by_key() {
+        let mut global = GlobalConfig::default();
+        global
+            .ui
+            .themes
+            .insert("ocean".to_string(), ThemeConfig::default());
+
+        let mut workspace = WorkspaceConfig::default();
+        let mut ui = UiOverrides::default();
+        ui.themes
+            .insert("forest".to_string(), ThemeConfig::default());
+        workspace.ui = Some(ui);
+
+        let merged = workspace.merge_over_global(&global);
+        assert!(merged.ui.themes.contains_key("ocean"));
+        assert!(merged.ui.themes.contains_key("forest"));
+    }
+
+    #[test]
+    fn test_workspace_theme_defined_globally_validates() {
+        let workspace = WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("ocean".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(workspace.validate().is_ok());
+
+        let mut global = GlobalConfig::default();
+        global
+            .ui
+            .themes
+            .insert("ocean".to_string(), ThemeConfig::default());
+        assert!(workspace.merge_over_global(&global).validate().is_ok());
+
+        // Without the global definition the merged configuration is invalid
+        assert!(workspace
+            .merge_over_global(&GlobalConfig::default())
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_override_fails_validation() {
+        let workspace = WorkspaceConfig {
+            ui: Some(UiOverrides {
+                layout: Some("cramped".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(workspace.validate().is_err());
+    }
+
+    #[test]
+    fn test_expand_variables() {
+        let toml_str = r#"
+            [metadata]
+            default_database = "${DATA_DIR}/main.db"
+
+            [metadata.environment]
+            DATA_DIR = "${ROOT}/data"
+
+            [[workspace.data_sources]]
+            name = "sales"
+            source_type = "sqlite"
+            connection = "sqlite://$DATA_DIR/$DB?cost=$$"
+        "#;
+        let mut config: WorkspaceConfig = toml::from_str(toml_str).unwrap();
+
+        // The workspace map wins over the process environment
+        let env = |name: &str| match name {
+            "ROOT" => Some("/srv".to_string()),
+            "DATA_DIR" => Some("/ignored".to_string()),
+            "DB" => Some("sales.db".to_string()),
+            _ => None,
+        };
+        config.expand_variables(env).unwrap();
+
+        assert_eq!(
+            config
+                .metadata
+                .environment
+                .get("DATA_DIR")
+                .map(String::as_str),
+            Some("/srv/data")
+        );
+        assert_eq!(
+            config.metadata.default_database.as_deref(),
+            Some("/srv/data/main.db")
+        );
+        assert_eq!(
+            config.workspace.data_sources[0].connection,
+            "sqlite:///srv/data/sales.db?cost=$"
+        );
+    }
+
+    #[test]
+    fn test_resolve_database_path() {
+        let root = Path::new("/work/sales");
+        let fallback = Path::new("/data/pixlie.db");
+        let mut config = WorkspaceConfig::default();
+
+        assert_eq!(
+            config.resolve_database_path(root, fallback).unwrap(),
+            fallback
+        );
+
+        config.workspace.data_sources.push(DataSourceConfig {
+            name: "orders".to_string(),
+            source_type: "sqlite".to_string(),
+            connection: "sqlite://db/orders.db".to_string(),
+            description: None,
+            read_only: true,
+            config: HashMap::new(),
+        });
+        assert_eq!(
+            config.resolve_database_path(root, fallback).unwrap(),
+            Path::new("/work/sales/db/orders.db")
+        );
+
+        config.metadata.default_database = Some("./main.db".to_string());
+        assert_eq!(
+            config.resolve_database_path(root, fallback).unwrap(),
+            Path::new("/work/sales/main.db")
+        );
+
+        // Absolute paths inside the global data folder are allowed
+        config.metadata.default_database = Some("/data/shared.db".to_string());
+        assert!(config.resolve_database_path(root, fallback).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_database_path_rejects_traversal() {
+        let mut config = WorkspaceConfig::default();
+        config.metadata.default_database = Some("../../etc/passwd".to_string());
+
+        let result =
+            config.resolve_database_path(Path::new("/work/sales"), Path::new("/data/pixlie.db"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_resolve_database_path_relative_root() {
+        let mut config = WorkspaceConfig::default();
+        config.workspace.data_sources.push(DataSourceConfig {
+            name: "system".to_string(),
+            source_type: "sqlite".to_string(),
+            connection: "sqlite:///etc/passwd".to_string(),
+            description: None,
+            read_only: true,
+            config: HashMap::new(),
+        });
+
+        // A workspace opened as "." must not allow arbitrary absolute paths
+        let result = config.resolve_database_path(Path::new("."), Path::new("pixlie.db"));
+        assert!(result.is_err());
+
+        config.workspace.data_sources[0].connection = "sqlite://db/app.db?mode=ro".to_string();
+        let path = config
+            .resolve_database_path(Path::new("."), Path::new("pixlie.db"))
+            .unwrap();
+        assert_eq!(path, std::env::current_dir().unwrap().join("db/app.db"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_database_path_rejects_symlink_escape() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let workspace_root = temp_dir.path().join("sales");
+        let outside = temp_dir.path().join("outside");
+        std::fs::create_dir_all(&workspace_root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, workspace_root.join("data")).unwrap();
+
+        let mut config = WorkspaceConfig::default();
+        config.metadata.default_database = Some("data/main.db".to_string());
+        let fallback = temp_dir.path().join("global").join("pixlie.db");
+        assert!(config
+            .resolve_database_path(&workspace_root, &fallback)
+            .is_err());
+
+        // Links that stay inside the workspace are followed
+        std::fs::create_dir(workspace_root.join("db")).unwrap();
+        std::os::unix::fs::symlink(workspace_root.join("db"), workspace_root.join("current"))
+            .unwrap();
+        config.metadata.default_database = Some("current/main.db".to_string());
+        let path = config
+            .resolve_database_path(&workspace_root, &fallback)
+            .unwrap();
+        assert_eq!(
+            path,
+            workspace_root.canonicalize().unwrap().join("db/main.db")
+        );
+    }
+
+    #[test]
+    fn test_expand_undefined_variable_fails() {
+        let mut config = WorkspaceConfig::default();
+        config.metadata.default_database = Some("${UNDEFINED_DIR}/main.db".to_string());
+
+        let err = config.expand_variables(|_| None).unwrap_err();
+        assert!(err.to_string().contains("UNDEFINED_DIR"));
+    }
+
     #[test]
     fn test_add_pinned_objective() {
         let mut config = WorkspaceConfig::default();
@@ -93,7 +93,7 @@ pub enum PixlieError {
     #[error("TUI error: {message}")]
     Tui {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -102,7 +102,7 @@ pub enum PixlieError {
     #[error("Session error: {message}")]
     Session {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -111,7 +111,7 @@ pub enum PixlieError {
     #[error("Database error: {message}")]
     Database {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -120,7 +120,7 @@ pub enum PixlieError {
     #[error("LLM provider error: {message}")]
     LlmProvider {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         provider: String,
         retryable: bool,
         #[source]
@@ -132,7 +132,7 @@ pub enum PixlieError {
     ToolExecution {
         tool_name: String,
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -141,7 +141,7 @@ pub enum PixlieError {
     #[error("Configuration error: {message}")]
     Configuration {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -150,7 +150,7 @@ pub enum PixlieError {
     #[error("Analysis error: {message}")]
     Analysis {
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -160,7 +160,7 @@ pub enum PixlieError {
     Validation {
         field: String,
         message: String,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
     },
 }
 
@@ -227,7 +227,7 @@ impl PixlieError {
     pub fn tui<S: Into<String>>(message: S, context: ErrorContext) -> Self {
         PixlieError::Tui {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -240,7 +240,7 @@ impl PixlieError {
     ) -> Self {
         PixlieError::Tui {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: Some(source),
         }
     }
@@ -249,7 +249,7 @@ impl PixlieError {
     pub fn session<S: Into<String>>(message: S, context: ErrorContext) -> Self {
         PixlieError::Session {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -258,7 +258,7 @@ impl PixlieError {
     pub fn database<S: Into<String>>(message: S, context: ErrorContext) -> Self {
         PixlieError::Database {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -274,7 +274,7 @@ impl PixlieError {
             message: message.into(),
             provider: provider.into(),
             retryable,
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -288,7 +288,7 @@ impl PixlieError {
         PixlieError::ToolExecution {
             tool_name: tool_name.into(),
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -297,7 +297,7 @@ impl PixlieError {
     pub fn configuration<S: Into<String>>(message: S, context: ErrorContext) -> Self {
         PixlieError::Configuration {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -306,7 +306,7 @@ impl PixlieError {
     pub fn analysis<S: Into<String>>(message: S, context: ErrorContext) -> Self {
         PixlieError::Analysis {
             message: message.into(),
-            context,
+            context: Box::new(context),
             source: None,
         }
     }
@@ -316,7 +316,7 @@ impl PixlieError {
         PixlieError::Validation {
             field: field.into(),
             message: message.into(),
-            context,
+            context: Box::new(context),
         }
     }
 }
@@ -373,17 +373,17 @@ impl From<std::io::Error> for PixlieError {
         match err.kind() {
             std::io::ErrorKind::NotFound => PixlieError::Session {
                 message: "File or directory not found".to_string(),
-                context,
+                context: Box::new(context),
                 source: Some(Box::new(err)),
             },
             std::io::ErrorKind::PermissionDenied => PixlieError::Session {
                 message: "Permission denied".to_string(),
-                context,
+                context: Box::new(context),
                 source: Some(Box::new(err)),
             },
             _ => PixlieError::Session {
                 message: format!("IO error: {}", err),
-                context,
+                context: Box::new(context),
                 source: Some(Box::new(err)),
             },
         }
@@ -396,7 +396,7 @@ impl From<serde_json::Error> for PixlieError {
         let context = ErrorContext::new().with_context("JSON serialization/deserialization failed");
         PixlieError::Session {
             message: format!("JSON error: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -408,7 +408,7 @@ impl From<tokio::task::JoinError> for PixlieError {
         let context = ErrorContext::new().with_context("Async task join failed");
         PixlieError::Analysis {
             message: format!("Task join error: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -421,7 +421,7 @@ impl From<uuid::Error> for PixlieError {
         PixlieError::Validation {
             field: "uuid".to_string(),
             message: format!("Invalid UUID: {}", err),
-            context,
+            context: Box::new(context),
         }
     }
 }
@@ -432,7 +432,7 @@ impl From<tracing_subscriber::util::TryInitError> for PixlieError {
         let context = ErrorContext::new().with_context("Logging initialization failed");
         PixlieError::Configuration {
             message: format!("Failed to initialize logging: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -444,7 +444,7 @@ impl From<std::env::VarError> for PixlieError {
         let context = ErrorContext::new().with_context("Environment variable access failed");
         PixlieError::Configuration {
             message: format!("Environment variable error: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -456,7 +456,7 @@ impl From<toml::de::Error> for PixlieError {
         let context = ErrorContext::new().with_context("TOML deserialization failed");
         PixlieError::Configuration {
             message: format!("TOML parsing error: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -468,7 +468,7 @@ impl From<toml::ser::Error> for PixlieError {
         let context = ErrorContext::new().with_context("TOML serialization failed");
         PixlieError::Configuration {
             message: format!("TOML serialization error: {}", err),
-            context,
+            context: Box::new(context),
             source: Some(Box::new(err)),
         }
     }
@@ -3,9 +3,14 @@
 //! Provides logging utilities for different components with proper
 //! context propagation and structured output.
 
+use crate::config::{LogFileConfig, UiConfig};
 use crate::error::{ErrorContext, ErrorSeverity, PixlieError};
-use std::io;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use tracing::{debug, error, info, trace, warn};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 use uuid::Uuid;
 
@@ -18,8 +23,8 @@ pub struct LoggingConfig {
     pub level: String,
     /// Enable colored output (for non-JSON format)
     pub colored: bool,
-    /// Log file path (optional)
-    pub file_path: Option<String>,
+    /// Rotating log file output (optional)
+    pub file: Option<LogFileConfig>,
 }
 
 impl Default for LoggingConfig {
@@ -28,30 +33,199 @@ impl Default for LoggingConfig {
             json_format: false,
             level: "info".to_string(),
             colored: true,
-            file_path: None,
+            file: None,
         }
     }
 }
 
+impl LoggingConfig {
+    /// Build logging configuration from the UI section of the global config
+    pub fn from_ui_config(ui: &UiConfig) -> Self {
+        Self {
+            json_format: ui.json_logs,
+            level: ui.log_level.clone(),
+            colored: ui.colored,
+            file: ui.log_file.clone(),
+        }
+    }
+}
+
+/// Keeps the background log file writer alive
+///
+/// Buffered file output is flushed when this guard is dropped, so it must be
+/// held until the application shuts down.
+#[must_use = "dropping the guard stops file logging"]
+pub struct LoggingGuard {
+    _file_guard: Option<WorkerGuard>,
+}
+
 /// Initialize the logging system with the given configuration
-pub fn init_logging(config: LoggingConfig) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+pub fn init_logging(
+    config: LoggingConfig,
+) -> Result<LoggingGuard, Box<dyn std::error::Error + Send + Sync>> {
     let env_filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.level));
 
-    let subscriber_builder = tracing_subscriber::registry().with(env_filter);
-
-    if config.json_format {
-        let json_layer = fmt::layer().json().with_writer(io::stderr);
-        subscriber_builder.with(json_layer).try_init()?;
-    } else {
-        let fmt_layer = fmt::layer()
+    let console = config.file.as_ref().is_none_or(|file| file.console);
+    let console_json =
+        (console && config.json_format).then(|| fmt::layer().json().with_writer(io::stderr));
+    let console_pretty = (console && !config.json_format).then(|| {
+        fmt::layer()
             .with_ansi(config.colored)
-            .with_writer(io::stderr);
-        subscriber_builder.with(fmt_layer).try_init()?;
-    }
+            .with_writer(io::stderr)
+    });
+
+    let (file_writer, file_guard) = match &config.file {
+        Some(file) => {
+            let (writer, guard) = tracing_appender::non_blocking(file_appender(file)?);
+            (Some(writer), Some(guard))
+        }
+        None => (None, None),
+    };
+    let file_json = file_writer
+        .clone()
+        .filter(|_| config.json_format)
+        .map(|writer| fmt::layer().json().with_writer(writer));
+    let file_pretty = file_writer
+        .filter(|_| !config.json_format)
+        .map(|writer| fmt::layer().with_ansi(false).with_writer(writer));
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(console_json)
+        .with(console_pretty)
+        .with(file_json)
+        .with(file_pretty)
+        .try_init()?;
 
     info!("Logging system initialized with level: {}", config.level);
-    Ok(())
+    if let Some(file) = &config.file {
+        info!(
+            directory = %file.directory.display(),
+            rotation = file.rotation,
+            max_size_mb = file.max_size_mb,
+            max_files = file.max_files,
+            "File logging enabled"
+        );
+    }
+
+    Ok(LoggingGuard {
+        _file_guard: file_guard,
+    })
+}
+
+/// Create the rolling file appender described by the log file configuration
+fn file_appender(
+    config: &LogFileConfig,
+) -> Result<Box<dyn Write + Send>, Box<dyn std::error::Error + Send + Sync>> {
+    let rotation = match config.rotation.as_str() {
+        "minutely" => Rotation::MINUTELY,
+        "hourly" => Rotation::HOURLY,
+        "daily" => Rotation::DAILY,
+        "never" => Rotation::NEVER,
+        "size" => {
+            let writer = SizeRollingWriter::new(
+                &config.directory,
+                &config.file_prefix,
+                config.max_size_mb * 1024 * 1024,
+                config.max_files,
+            )?;
+            return Ok(Box::new(writer));
+        }
+        other => return Err(format!("Unsupported log rotation '{}'", other).into()),
+    };
+
+    let appender = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(&config.file_prefix)
+        .max_log_files(config.max_files)
+        .build(&config.directory)?;
+
+    Ok(Box::new(appender))
+}
+
+/// Log file writer that rotates once the active file reaches a size limit
+///
+/// The active file is `<directory>/<prefix>`. On rotation it becomes
+/// `<prefix>.1`, earlier rotations shift up by one, and files beyond
+/// `max_files` in total are removed.
+pub struct SizeRollingWriter {
+    directory: PathBuf,
+    prefix: String,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl SizeRollingWriter {
+    /// Open (or continue) the active log file in `directory`
+    pub fn new(
+        directory: &Path,
+        prefix: &str,
+        max_bytes: u64,
+        max_files: usize,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let path = directory.join(prefix);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            prefix: prefix.to_string(),
+            max_bytes,
+            max_files: max_files.max(1),
+            file,
+            size,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        if index == 0 {
+            self.directory.join(&self.prefix)
+        } else {
+            self.directory.join(format!("{}.{}", self.prefix, index))
+        }
+    }
+
+    /// Shift the rotated files up by one and start a new active file
+    fn roll(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        let _ = fs::remove_file(self.rotated_path(self.max_files - 1));
+        for index in (1..self.max_files).rev() {
+            let from = self.rotated_path(index - 1);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index))?;
+            }
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(self.rotated_path(0))?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A single oversized record still goes to its own file
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.roll()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
 }
 
 /// Component-specific logger for TUI operations
@@ -452,7 +626,68 @@ mod tests {
         assert!(!config.json_format);
         assert_eq!(config.level, "info");
         assert!(config.colored);
-        assert!(config.file_path.is_none());
+        assert!(config.file.is_none());
+    }
+
+    #[test]
+    fn test_logging_config_from_ui_config() {
+        let ui = UiConfig {
+            json_logs: true,
+            log_level: "debug".to_string(),
+            log_file: Some(LogFileConfig::default()),
+            ..Default::default()
+        };
+
+        let config = LoggingConfig::from_ui_config(&ui);
+        assert!(config.json_format);
+        assert_eq!(config.level, "debug");
+        assert!(config.file.is_some());
+    }
+
+    #[test]
+    fn test_file_appender_writes_to_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = LogFileConfig {
+            directory: temp_dir.path().join("logs"),
+            rotation: "never".to_string(),
+            ..Default::default()
+        };
+
+        let mut appender = file_appender(&config).unwrap();
+        appender.write_all(b"hello\n").unwrap();
+        appender.flush().unwrap();
+
+        let contents = std::fs::read_to_string(temp_dir.path().join("logs").join("pixlie.log"));
+        assert_eq!(contents.unwrap(), "hello\n");
+
+        let invalid = LogFileConfig {
+            rotation: "weekly".to_string(),
+            ..config
+        };
+        assert!(file_appender(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_size_rolling_writer_rotates_and_prunes() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let logs = temp_dir.path().join("logs");
+        let mut writer = SizeRollingWriter::new(&logs, "pixlie.log", 10, 3).unwrap();
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let read = |name: &str| std::fs::read_to_string(logs.join(name)).unwrap();
+        assert_eq!(read("pixlie.log"), "fourth\n");
+        assert_eq!(read("pixlie.log.1"), "third\n");
+        assert_eq!(read("pixlie.log.2"), "second\n");
+        assert!(!logs.join("pixlie.log.3").exists());
+
+        // Reopening continues the active file instead of truncating it
+        let mut writer = SizeRollingWriter::new(&logs, "pixlie.log", 10, 3).unwrap();
+        writer.write_all(b"5\n").unwrap();
+        assert_eq!(read("pixlie.log"), "fourth\n5\n");
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -12,6 +12,7 @@ use pixlie::{
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 use std::io;
+use std::path::Path;
 use std::process;
 use tracing::{debug, info};
 
@@ -19,12 +20,43 @@ use tracing::{debug, info};
 #[command(name = "pixlie")]
 #[command(about = "LLM-enabled TUI data analysis tool for SQLite databases")]
 #[command(version = "0.1.0")]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Optional workspace path to open on startup
     #[arg(value_name = "WORKSPACE")]
     pub workspace: Option<String>,
 }
 
+#[derive(Subcommand)]
+pub enum Command {
+    /// Check the global and workspace configuration without starting the TUI
+    Validate {
+        /// Workspace to check (defaults to the workspace containing the current directory)
+        #[arg(value_name = "WORKSPACE")]
+        workspace: Option<String>,
+    },
+}
+
+/// Print a validation report and return whether the configuration is valid
+async fn run_validate(workspace: Option<String>) -> Result<bool> {
+    use pixlie::config::ValidationReport;
+    use pixlie::ConfigLoader;
+
+    let workspace = match workspace {
+        Some(workspace) => Some(workspace),
+        None => detect_workspace_in_current_dir().await?,
+    };
+
+    let loader = ConfigLoader::new()?;
+    let report = ValidationReport::check(&loader, workspace.as_deref().map(Path::new)).await;
+    println!("{}", report);
+
+    Ok(report.is_ok())
+}
+
 async fn run_application(args: Args) -> Result<()> {
     let _context = ErrorContext::new().with_context("Application startup");
 
@@ -50,6 +82,9 @@ async fn run_application(args: Args) -> Result<()> {
     // Load configuration from environment and files (no CLI args to override)
     load_basic_configuration(&mut config_manager).await?;
 
+    // Check the workspace against the global configuration it is merged over
+    config_manager.validate()?;
+
     // Get effective configuration for TUI startup
     let ui_config = config_manager.effective_ui_config();
     let _session_config = config_manager.effective_session_config();
@@ -169,14 +204,7 @@ async fn run_tui_loop(
         if let Some(event) = event_handler.next().await {
             match event {
                 Event::Key(key_event) => {
-                    // Handle special key combinations
-                    if key_event.code == crossterm::event::KeyCode::F(12) {
-                        app.toggle_settings();
-                    } else if key_event.code == crossterm::event::KeyCode::F(11) {
-                        app.open_workspace_manager().await;
-                    } else {
-                        app.handle_key(key_event.code).await?;
-                    }
+                    app.handle_key_event(key_event).await?;
                 }
                 Event::Resize(_, _) => {
                     // Terminal was resized, will be handled on next draw
@@ -210,13 +238,7 @@ fn render_ui(frame: &mut Frame, app: &mut App) {
         }
         pixlie::tui::AppMode::Settings => {
             render_normal_mode(frame, app, area); // Render background
-
-            // Get config manager for settings modal
-            let config_manager = tokio::task::block_in_place(|| {
-                tokio::runtime::Handle::current().block_on(async { app.get_config_manager().await })
-            });
-
-            SettingsModal::render(frame, app, config_manager, area);
+            SettingsModal::render(frame, app, area);
         }
         pixlie::tui::AppMode::WorkspaceManager => {
             render_normal_mode(frame, app, area); // Render background
@@ -230,12 +252,12 @@ fn render_ui(frame: &mut Frame, app: &mut App) {
 
 /// Render normal mode (main interface)
 fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
-    let workspace_info = tokio::task::block_in_place(|| {
+    let (workspace_info, shortcuts) = tokio::task::block_in_place(|| {
         tokio::runtime::Handle::current().block_on(async {
             let config = app.get_config_manager().await;
             let config = config.read().await;
 
-            if let Some(workspace_config) = &config.workspace {
+            let workspace_info = if let Some(workspace_config) = &config.workspace {
                 let workspace_name = workspace_config
                     .metadata
                     .name
@@ -246,7 +268,12 @@ fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
                 Some((workspace_name, objectives_count))
             } else {
                 None
-            }
+            };
+
+            (
+                workspace_info,
+                config.effective_shortcuts_config().into_owned(),
+            )
         })
     });
 
@@ -256,23 +283,27 @@ fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
         "Pixlie - LLM Data Analysis Tool".to_string()
     };
 
-    let main_block = Block::default().title(main_title).borders(Borders::ALL);
+    let theme = app.theme();
+    let main_block = Block::default()
+        .title(main_title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent));
 
     let mut content_lines = vec![Line::from("Welcome to Pixlie!"), Line::from("")];
 
     if let Some((workspace_name, objectives_count)) = workspace_info {
         content_lines.extend(vec![
             Line::from(vec![
-                Span::styled("Current Workspace: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Current Workspace: ", Style::default().fg(theme.accent)),
                 Span::styled(
                     workspace_name,
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.highlight)
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
             Line::from(vec![
-                Span::styled("Pinned Objectives: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Pinned Objectives: ", Style::default().fg(theme.accent)),
                 Span::raw(objectives_count.to_string()),
             ]),
             Line::from(""),
@@ -281,7 +312,7 @@ fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
         content_lines.extend(vec![
             Line::from(Span::styled(
                 "No workspace loaded",
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.error),
             )),
             Line::from(""),
         ]);
@@ -289,9 +320,12 @@ fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
 
     content_lines.extend(vec![
         Line::from("Keyboard Shortcuts:"),
-        Line::from("• Ctrl+W: Open Workspace Manager"),
-        Line::from("• Ctrl+,: Open Settings"),
-        Line::from("• Ctrl+Q: Quit"),
+        Line::from(format!(
+            "• {}: Open Workspace Manager",
+            shortcuts.workspace_manager
+        )),
+        Line::from(format!("• {}: Open Settings", shortcuts.settings)),
+        Line::from(format!("• {}: Quit", shortcuts.quit)),
         Line::from(""),
         Line::from("This is the main interface placeholder."),
         Line::from("The workspace management system is now ready!"),
@@ -299,6 +333,7 @@ fn render_normal_mode(frame: &mut Frame, app: &App, area: Rect) {
 
     let content = Paragraph::new(content_lines)
         .block(main_block)
+        .style(Style::default().fg(theme.foreground).bg(theme.background))
         .wrap(ratatui::widgets::Wrap { trim: true });
 
     frame.render_widget(content, area);
@@ -344,6 +379,25 @@ async fn load_basic_configuration(config_manager: &mut ConfigManager) -> Result<
     Ok(())
 }
 
+/// Load logging settings from the global configuration
+///
+/// Errors are ignored here; they resurface when the configuration is loaded
+/// for the application, after logging is available to report them.
+async fn load_logging_configuration() -> LoggingConfig {
+    use pixlie::ConfigLoader;
+
+    let Ok(loader) = ConfigLoader::new() else {
+        return LoggingConfig::default();
+    };
+
+    let mut global = loader.load_global_config().await.unwrap_or_default();
+    if loader.apply_environment_overrides(&mut global).is_err() {
+        return LoggingConfig::default();
+    }
+
+    LoggingConfig::from_ui_config(&global.ui)
+}
+
 /// Detect if current directory or parent directories contain a workspace
 async fn detect_workspace_in_current_dir() -> Result<Option<String>> {
     let current_dir = std::env::current_dir().map_err(|e| {
@@ -376,20 +430,31 @@ async fn detect_workspace_in_current_dir() -> Result<Option<String>> {
 
 #[tokio::main]
 async fn main() {
-    let args = Args::parse();
-
-    // Initialize basic logging system with defaults
-    // The configuration system will be loaded inside run_application
-    let logging_config = LoggingConfig {
-        json_format: false,
-        level: "info".to_string(),
-        colored: true,
-        file_path: None,
+    let mut args = Args::parse();
+
+    // Initialize logging from the global configuration (falling back to
+    // defaults); the full configuration is loaded inside run_application
+    let logging_config = load_logging_configuration().await;
+
+    let logging_guard = match init_logging(logging_config) {
+        Ok(guard) => guard,
+        Err(e) => {
+            eprintln!("Failed to initialize logging: {}", e);
+            process::exit(1);
+        }
     };
 
-    if let Err(e) = init_logging(logging_config) {
-        eprintln!("Failed to initialize logging: {}", e);
-        process::exit(1);
+    if let Some(Command::Validate { workspace }) = args.command.take() {
+        let valid = match run_validate(workspace).await {
+            Ok(valid) => valid,
+            Err(e) => {
+                eprintln!("Error: {}", e.user_message());
+                false
+            }
+        };
+
+        drop(logging_guard);
+        process::exit(if valid { 0 } else { 1 });
     }
 
     // Run the application and handle errors
@@ -407,6 +472,8 @@ async fn main() {
             ErrorSeverity::Critical => 4,
         };
 
+        // process::exit skips destructors, so flush file logs first
+        drop(logging_guard);
         process::exit(exit_code);
     }
 }
@@ -1,7 +1,9 @@
 use crate::config::ConfigManager;
-use crate::tui::components::{WorkspaceManager, WorkspacePicker};
+use crate::tui::components::{SettingsField, WorkspaceManager, WorkspacePicker};
+use crate::tui::keymap::{Keymap, ShortcutAction};
+use crate::tui::Theme;
 use crate::{ErrorContext, PixlieError, Result};
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -54,15 +56,31 @@ impl SettingsTab {
     }
 }
 
+/// A change made in the settings modal
+///
+/// Edits are replayed onto the configuration as stored on disk when saving,
+/// so values that only exist at runtime are never written out.
+#[derive(Debug, Clone, PartialEq)]
+enum SettingsEdit {
+    Field(SettingsField),
+    Reset(SettingsTab),
+}
+
 pub struct App {
     config_manager: Arc<RwLock<ConfigManager>>,
     mode: AppMode,
     settings_tab: SettingsTab,
+    settings_field: usize,
+    settings_draft: Option<ConfigManager>,
+    settings_edits: Vec<SettingsEdit>,
+    settings_error: Option<String>,
     should_quit: bool,
     settings_modified: bool,
     workspace_picker: Option<WorkspacePicker>,
     workspace_manager: Option<WorkspaceManager>,
     needs_workspace_picker: bool,
+    theme: Theme,
+    keymap: Keymap,
 }
 
 impl App {
@@ -74,15 +92,27 @@ impl App {
             AppMode::Normal
         };
 
+        // Configuration is validated on load, so an unresolvable theme only
+        // happens for hand-built configs; fall back to the default palette
+        let theme = Theme::from_config(&config_manager.effective_ui_config()).unwrap_or_default();
+        let keymap =
+            Keymap::from_config(&config_manager.effective_shortcuts_config()).unwrap_or_default();
+
         Self {
             config_manager: Arc::new(RwLock::new(config_manager)),
             mode: initial_mode,
             settings_tab: SettingsTab::Ui,
+            settings_field: 0,
+            settings_draft: None,
+            settings_edits: Vec::new(),
+            settings_error: None,
             should_quit: false,
             settings_modified: false,
             workspace_picker: None,
             workspace_manager: None,
             needs_workspace_picker,
+            theme,
+            keymap,
         }
     }
 
@@ -94,6 +124,14 @@ impl App {
         &self.mode
     }
 
+    pub fn needs_workspace_picker(&self) -> bool {
+        self.needs_workspace_picker
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     pub fn settings_tab(&self) -> &SettingsTab {
         &self.settings_tab
     }
@@ -102,25 +140,51 @@ impl App {
         self.settings_modified
     }
 
+    /// Index of the selected field on the current settings tab
+    pub fn settings_field(&self) -> usize {
+        self.settings_field
+    }
+
+    /// Unsaved copy of the configuration being edited in the settings modal
+    ///
+    /// Rendering reads the draft directly so it never waits on the shared
+    /// configuration lock.
+    pub fn settings_draft(&self) -> Option<&ConfigManager> {
+        self.settings_draft.as_ref()
+    }
+
+    /// Why the last settings change was rejected, if it was
+    pub fn settings_error(&self) -> Option<&str> {
+        self.settings_error.as_deref()
+    }
+
     pub fn quit(&mut self) {
         self.should_quit = true;
     }
 
-    pub fn toggle_settings(&mut self) {
-        self.mode = match self.mode {
-            AppMode::Normal => AppMode::Settings,
-            AppMode::Settings => AppMode::Normal,
-            AppMode::WorkspacePicker => AppMode::Settings,
-            AppMode::WorkspaceManager => AppMode::Settings,
-        };
+    pub async fn toggle_settings(&mut self) {
+        match self.mode {
+            AppMode::Settings => self.close_settings(),
+            _ => self.open_settings().await,
+        }
     }
 
-    pub fn open_settings(&mut self) {
+    /// Open the settings modal on a draft of the current configuration
+    pub async fn open_settings(&mut self) {
+        self.settings_draft = Some(self.config_manager.read().await.clone());
+        self.settings_edits.clear();
+        self.settings_field = 0;
+        self.settings_error = None;
+        self.settings_modified = false;
         self.mode = AppMode::Settings;
     }
 
+    /// Close the settings modal, discarding unsaved changes
     pub fn close_settings(&mut self) {
         self.mode = AppMode::Normal;
+        self.settings_draft = None;
+        self.settings_edits.clear();
+        self.settings_error = None;
         self.settings_modified = false;
     }
 
@@ -130,6 +194,7 @@ impl App {
         }
 
         if let Some(picker) = &mut self.workspace_picker {
+            picker.set_theme(self.theme);
             let _ = picker.load_recent_workspaces().await;
         }
 
@@ -147,6 +212,12 @@ impl App {
         }
 
         if let Some(manager) = &mut self.workspace_manager {
+            manager.set_theme(self.theme);
+            let scan_depth = {
+                let config = self.config_manager.read().await;
+                config.effective_session_config().workspace_scan_depth
+            };
+            manager.set_scan_depth(scan_depth);
             let _ = manager.load_workspaces().await;
 
             // Set current workspace info if available
@@ -180,17 +251,53 @@ impl App {
         self.mode = AppMode::WorkspaceManager;
     }
 
+    /// Hide the workspace manager
+    ///
+    /// The manager is kept so the wizard's input history is still there the
+    /// next time it opens; its workspace list is reloaded on open.
     pub fn close_workspace_manager(&mut self) {
         self.mode = AppMode::Normal;
-        self.workspace_manager = None;
     }
 
     pub fn next_settings_tab(&mut self) {
         self.settings_tab = self.settings_tab.next();
+        self.settings_field = 0;
     }
 
     pub fn previous_settings_tab(&mut self) {
         self.settings_tab = self.settings_tab.previous();
+        self.settings_field = 0;
+    }
+
+    /// Dispatch a key event, resolving configured shortcuts first
+    ///
+    /// Shortcuts without Ctrl, Alt or Super, other than function keys, only
+    /// apply in normal mode so they don't swallow text typed into dialogs.
+    pub async fn handle_key_event(&mut self, event: KeyEvent) -> Result<()> {
+        let is_chord = event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SUPER)
+            || matches!(event.code, KeyCode::F(_));
+
+        let action = self
+            .keymap
+            .action_for(&event)
+            .filter(|_| is_chord || self.mode == AppMode::Normal);
+
+        match action {
+            Some(ShortcutAction::Quit) => self.quit(),
+            Some(ShortcutAction::Settings) => self.toggle_settings().await,
+            Some(ShortcutAction::WorkspaceManager) => self.open_workspace_manager().await,
+            Some(ShortcutAction::Send) => self.handle_key(KeyCode::Enter).await?,
+            Some(ShortcutAction::NavUp) => self.handle_key(KeyCode::Up).await?,
+            Some(ShortcutAction::NavDown) => self.handle_key(KeyCode::Down).await?,
+            Some(ShortcutAction::NavLeft) => self.handle_key(KeyCode::Left).await?,
+            Some(ShortcutAction::NavRight) => self.handle_key(KeyCode::Right).await?,
+            // Remaining actions belong to views that don't exist yet
+            _ => self.handle_key(event.code).await?,
+        }
+
+        Ok(())
     }
 
     pub async fn handle_key(&mut self, key: KeyCode) -> Result<()> {
@@ -243,40 +350,21 @@ impl App {
             self.load_workspace(&path).await?;
             self.close_workspace_picker();
         }
-        
+
         Ok(())
     }
 
-    async fn handle_normal_mode_key(&mut self, key: KeyCode) -> Result<()> {
-        let quit_key = {
-            let config = self.config_manager.read().await;
-            let shortcuts = config.effective_shortcuts_config();
-            shortcuts.quit.clone()
-        };
-
-        match key {
-            KeyCode::Char('q') => {
-                if quit_key == "q" {
-                    self.quit();
-                }
-            }
-            KeyCode::Char('w') => {
-                // Ctrl+W is handled by the event handler for workspace manager
-            }
-            KeyCode::Char(',') => {
-                // Ctrl+, is handled by the event handler
-                // This is just the ',' character
-            }
-            _ => {}
-        }
+    async fn handle_normal_mode_key(&mut self, _key: KeyCode) -> Result<()> {
+        // Shortcuts are resolved by handle_key_event; the main view has no
+        // other key handling yet
         Ok(())
     }
 
     async fn handle_workspace_manager_key(&mut self, key: KeyCode) -> Result<()> {
-        let (mode, workspace_path) = if let Some(manager) = &mut self.workspace_manager {
+        let workspace_path = if let Some(manager) = &mut self.workspace_manager {
             let mode = manager.mode().clone();
             let mut workspace_path = None;
-            
+
             match mode {
                 crate::tui::components::workspace_manager::WorkspaceManagerMode::List => {
                     match key {
@@ -356,10 +444,54 @@ impl App {
                             manager.next_create_step();
                             return Ok(());
                         }
+                        KeyCode::Tab => {
+                            manager.next_create_field();
+                            return Ok(());
+                        }
+                        KeyCode::BackTab => {
+                            manager.previous_create_step();
+                            return Ok(());
+                        }
+                        KeyCode::Backspace if manager.create_step_has_input() => {
+                            manager.create_backspace();
+                            return Ok(());
+                        }
                         KeyCode::Backspace => {
                             manager.previous_create_step();
                             return Ok(());
                         }
+                        KeyCode::Delete if manager.create_step_has_input() => {
+                            manager.create_delete();
+                            return Ok(());
+                        }
+                        KeyCode::Up if manager.create_step_has_input() => {
+                            manager.create_history_previous();
+                            return Ok(());
+                        }
+                        KeyCode::Down if manager.create_step_has_input() => {
+                            manager.create_history_next();
+                            return Ok(());
+                        }
+                        KeyCode::Left if manager.create_step_has_input() => {
+                            manager.create_cursor_left();
+                            return Ok(());
+                        }
+                        KeyCode::Right if manager.create_step_has_input() => {
+                            manager.create_cursor_right();
+                            return Ok(());
+                        }
+                        KeyCode::Home if manager.create_step_has_input() => {
+                            manager.create_cursor_home();
+                            return Ok(());
+                        }
+                        KeyCode::End if manager.create_step_has_input() => {
+                            manager.create_cursor_end();
+                            return Ok(());
+                        }
+                        KeyCode::Char(c) if manager.create_step_has_input() => {
+                            manager.create_input_char(c);
+                            return Ok(());
+                        }
                         KeyCode::Esc => {
                             manager.return_to_list();
                             return Ok(());
@@ -384,8 +516,19 @@ impl App {
                 crate::tui::components::workspace_manager::WorkspaceManagerMode::Delete => {
                     match key {
                         KeyCode::Char('y') | KeyCode::Char('Y') => {
-                            // TODO: Delete workspace
-                            manager.return_to_list();
+                            let deleted = manager.delete_selected_workspace().await?;
+
+                            // Unload the workspace so a later save doesn't recreate its config
+                            if let Some(deleted) = deleted {
+                                let deleted_config = deleted.path.join(".pixlie-workspace.toml");
+                                let mut config = self.config_manager.write().await;
+                                if config.paths.workspace_config.as_deref()
+                                    == Some(deleted_config.as_path())
+                                {
+                                    config.workspace = None;
+                                    config.paths.workspace_config = None;
+                                }
+                            }
                             return Ok(());
                         }
                         KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
@@ -396,8 +539,8 @@ impl App {
                     }
                 }
             }
-            
-            (mode, workspace_path)
+
+            workspace_path
         } else {
             return Ok(());
         };
@@ -428,80 +571,120 @@ impl App {
             KeyCode::Char('q') => {
                 self.close_settings();
             }
-            _ => {
-                // Handle setting-specific inputs
-                self.handle_setting_input(key).await?;
+            KeyCode::Up => {
+                self.settings_field = self.settings_field.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                let count = SettingsField::for_tab(&self.settings_tab).len();
+                if self.settings_field + 1 < count {
+                    self.settings_field += 1;
+                }
+            }
+            KeyCode::Left => {
+                self.adjust_setting(false);
             }
+            KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => {
+                self.adjust_setting(true);
+            }
+            KeyCode::Char('s') => {
+                if let Err(e) = self.save_settings().await {
+                    self.settings_error = Some(e.user_message());
+                }
+            }
+            KeyCode::Char('r') => {
+                self.reset_settings_to_defaults().await?;
+            }
+            _ => {}
         }
         Ok(())
     }
 
-    async fn handle_setting_input(&mut self, key: KeyCode) -> Result<()> {
-        match self.settings_tab {
-            SettingsTab::Ui => self.handle_ui_setting_input(key).await,
-            SettingsTab::Session => self.handle_session_setting_input(key).await,
-            SettingsTab::Llm => self.handle_llm_setting_input(key).await,
-            SettingsTab::Database => self.handle_database_setting_input(key).await,
-            SettingsTab::Shortcuts => self.handle_shortcuts_setting_input(key).await,
-        }
-    }
-
-    async fn handle_ui_setting_input(&mut self, _key: KeyCode) -> Result<()> {
-        // TODO: Implement UI setting input handling
-        Ok(())
-    }
-
-    async fn handle_session_setting_input(&mut self, _key: KeyCode) -> Result<()> {
-        // TODO: Implement session setting input handling
-        Ok(())
-    }
-
-    async fn handle_llm_setting_input(&mut self, _key: KeyCode) -> Result<()> {
-        // TODO: Implement LLM setting input handling
-        Ok(())
-    }
+    /// Step the selected field in the settings draft
+    ///
+    /// The change is kept only if the resulting configuration validates;
+    /// otherwise the draft is left as it was and the error is shown.
+    pub fn adjust_setting(&mut self, forward: bool) {
+        let Some(field) = SettingsField::for_tab(&self.settings_tab)
+            .get(self.settings_field)
+            .copied()
+        else {
+            return;
+        };
+        let Some(draft) = &self.settings_draft else {
+            return;
+        };
 
-    async fn handle_database_setting_input(&mut self, _key: KeyCode) -> Result<()> {
-        // TODO: Implement database setting input handling
-        Ok(())
-    }
+        let mut candidate = draft.clone();
+        field.adjust(&mut candidate, forward);
 
-    async fn handle_shortcuts_setting_input(&mut self, _key: KeyCode) -> Result<()> {
-        // TODO: Implement shortcuts setting input handling
-        Ok(())
+        match candidate.validate() {
+            Ok(()) => {
+                self.settings_draft = Some(candidate);
+                self.settings_edits.push(SettingsEdit::Field(field));
+                self.settings_error = None;
+                self.settings_modified = true;
+            }
+            Err(e) => self.settings_error = Some(e.user_message()),
+        }
     }
 
+    /// Write the settings edits to disk, then apply them to the live configuration
+    ///
+    /// The live configuration includes environment and CLI overrides, so the
+    /// edits are replayed onto the configuration as stored on disk instead
+    /// of saving the live one. Nothing changes in memory if the save fails.
     pub async fn save_settings(&mut self) -> Result<()> {
-        let config = self.config_manager.write().await;
-        config.save().await.map_err(|e| {
+        let Some(draft) = &self.settings_draft else {
+            return Ok(());
+        };
+        draft.validate()?;
+
+        let mut persisted = self.config_manager.read().await.load_persisted().await?;
+        let edited = draft.effective_config();
+        for edit in &self.settings_edits {
+            match edit {
+                SettingsEdit::Field(field) => field.assign(&mut persisted, &edited),
+                SettingsEdit::Reset(tab) => reset_tab(&mut persisted, tab),
+            }
+        }
+        persisted.validate()?;
+
+        persisted.save().await.map_err(|e| {
             PixlieError::configuration(
                 format!("Failed to save settings: {}", e),
                 ErrorContext::new().with_context("Settings save"),
             )
         })?;
+
+        // The saved workspace keeps its variable references; the live one
+        // holds them expanded, as after loading
+        let mut workspace = persisted.workspace;
+        if let Some(workspace) = &mut workspace {
+            workspace.expand_variables(|name| std::env::var(name).ok())?;
+        }
+
+        let mut config = self.config_manager.write().await;
+        config.global = draft.global.clone();
+        config.workspace = workspace;
+
+        self.theme = Theme::from_config(&config.effective_ui_config())?;
+        self.keymap = Keymap::from_config(&config.effective_shortcuts_config())?;
+        self.settings_draft = Some(config.clone());
+        self.settings_edits.clear();
+        self.settings_error = None;
         self.settings_modified = false;
         Ok(())
     }
 
+    /// Reset the current tab's settings in the draft, global and workspace
     pub async fn reset_settings_to_defaults(&mut self) -> Result<()> {
-        let mut config = self.config_manager.write().await;
-        match self.settings_tab {
-            SettingsTab::Ui => {
-                config.global.ui = Default::default();
-            }
-            SettingsTab::Session => {
-                config.global.session = Default::default();
-            }
-            SettingsTab::Llm => {
-                config.global.llm = Default::default();
-            }
-            SettingsTab::Database => {
-                config.global.database = Default::default();
-            }
-            SettingsTab::Shortcuts => {
-                config.global.shortcuts = Default::default();
-            }
-        }
+        let Some(config) = &mut self.settings_draft else {
+            return Ok(());
+        };
+        reset_tab(config, &self.settings_tab);
+        self.settings_edits
+            .push(SettingsEdit::Reset(self.settings_tab.clone()));
+        self.settings_error = None;
         self.settings_modified = true;
         Ok(())
     }
@@ -527,8 +710,182 @@ impl App {
             let mut config = self.config_manager.write().await;
             config.workspace = Some(workspace_config);
             config.paths.workspace_config = Some(workspace_path.join(".pixlie-workspace.toml"));
+            self.theme = Theme::from_config(&config.effective_ui_config())?;
+            self.keymap = Keymap::from_config(&config.effective_shortcuts_config())?;
         }
 
         Ok(())
     }
 }
+
+/// Restore the defaults for the settings shown on a tab
+///
+/// The workspace's overrides for the tab's section are dropped as well,
+/// otherwise they would keep masking the reset global values.
+fn reset_tab(config: &mut ConfigManager, tab: &SettingsTab) {
+    let global = &mut config.global;
+    match tab {
+        SettingsTab::Ui => global.ui = Default::default(),
+        SettingsTab::Session => global.session = Default::default(),
+        SettingsTab::Llm => global.llm = Default::default(),
+        SettingsTab::Database => global.database = Default::default(),
+        SettingsTab::Shortcuts => global.shortcuts = Default::default(),
+    }
+
+    if let Some(workspace) = &mut config.workspace {
+        match tab {
+            SettingsTab::Ui => workspace.ui = None,
+            SettingsTab::Session => workspace.session = None,
+            SettingsTab::Llm => workspace.llm = None,
+            SettingsTab::Database => workspace.database = None,
+            SettingsTab::Shortcuts => workspace.shortcuts = None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GlobalConfig, UiOverrides, WorkspaceConfig};
+
+    #[tokio::test]
+    async fn test_editing_keys_ignored_on_create_steps_without_input() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut app = App::new(ConfigManager::new().unwrap());
+        app.workspace_manager = Some(WorkspaceManager::new());
+        app.mode = AppMode::WorkspaceManager;
+
+        let manager = app.workspace_manager().unwrap();
+        manager.enter_create_mode();
+        "Sales".chars().for_each(|c| manager.create_input_char(c));
+        manager.next_create_field();
+        while !manager.create_field_value().is_empty() {
+            manager.create_backspace();
+        }
+        let path = temp_dir.path().join("sales").to_string_lossy().to_string();
+        path.chars().for_each(|c| manager.create_input_char(c));
+        manager.next_create_step();
+        "Demo".chars().for_each(|c| manager.create_input_char(c));
+        manager.next_create_step();
+
+        for key in [
+            KeyCode::Left,
+            KeyCode::Delete,
+            KeyCode::Home,
+            KeyCode::Delete,
+        ] {
+            app.handle_key(key).await.unwrap();
+        }
+
+        let manager = app.workspace_manager().unwrap();
+        manager.previous_create_step();
+        assert_eq!(manager.create_field_value(), "Demo");
+    }
+
+    #[tokio::test]
+    async fn test_changed_theme_round_trips_to_config_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+
+        let mut manager = ConfigManager::new().unwrap();
+        manager.paths.global_config = config_path.clone();
+        let mut app = App::new(manager);
+
+        app.open_settings().await;
+        app.handle_key(KeyCode::Right).await.unwrap();
+        assert!(app.settings_modified());
+        app.handle_key(KeyCode::Char('s')).await.unwrap();
+
+        assert!(!app.settings_modified());
+        assert_eq!(app.theme(), &Theme::light());
+
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        let saved: GlobalConfig = toml::from_str(&content).unwrap();
+        assert_eq!(saved.ui.theme, "light");
+    }
+
+    #[tokio::test]
+    async fn test_saving_settings_keeps_runtime_overrides_out_of_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        std::fs::write(&config_path, "[llm]\ntemperature = 0.3\n").unwrap();
+
+        // Values such as PIXLIE_DEFAULT_MODEL only exist in the live config
+        let mut manager = ConfigManager::new().unwrap();
+        manager.paths.global_config = config_path.clone();
+        manager.global.llm.default_model = "from-env".to_string();
+        let mut app = App::new(manager);
+
+        app.open_settings().await;
+        app.handle_key(KeyCode::Right).await.unwrap();
+        app.handle_key(KeyCode::Char('s')).await.unwrap();
+
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        let saved: GlobalConfig = toml::from_str(&content).unwrap();
+        assert_eq!(saved.ui.theme, "light");
+        assert_eq!(saved.llm.temperature, 0.3);
+        assert_eq!(saved.llm.default_model, "gpt-3.5-turbo");
+
+        let config = app.config_manager.read().await;
+        assert_eq!(config.global.ui.theme, "light");
+        assert_eq!(config.global.llm.default_model, "from-env");
+    }
+
+    #[tokio::test]
+    async fn test_failed_settings_save_leaves_live_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let blocker = temp_dir.path().join("not-a-directory");
+        std::fs::write(&blocker, "").unwrap();
+
+        let mut manager = ConfigManager::new().unwrap();
+        manager.paths.global_config = blocker.join("config.toml");
+        let mut app = App::new(manager);
+
+        app.open_settings().await;
+        app.handle_key(KeyCode::Right).await.unwrap();
+        app.handle_key(KeyCode::Char('s')).await.unwrap();
+
+        assert!(app.settings_error().is_some());
+        assert!(app.settings_modified());
+        assert_eq!(app.theme(), &Theme::dark());
+        let config = app.config_manager.read().await;
+        assert_eq!(config.global.ui.theme, "dark");
+    }
+
+    #[tokio::test]
+    async fn test_reset_clears_workspace_overrides() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut manager = ConfigManager::new().unwrap();
+        manager.paths.global_config = temp_dir.path().join("config.toml");
+        manager.paths.workspace_config = Some(temp_dir.path().join(".pixlie-workspace.toml"));
+        manager.workspace = Some(WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("light".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let mut app = App::new(manager);
+
+        app.open_settings().await;
+        app.handle_key(KeyCode::Char('r')).await.unwrap();
+        app.handle_key(KeyCode::Char('s')).await.unwrap();
+
+        assert_eq!(app.theme(), &Theme::dark());
+        let config = app.config_manager.read().await;
+        assert!(config.workspace.as_ref().unwrap().ui.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_closing_settings_discards_draft() {
+        let mut app = App::new(ConfigManager::new().unwrap());
+
+        app.open_settings().await;
+        app.handle_key(KeyCode::Right).await.unwrap();
+        app.handle_key(KeyCode::Esc).await.unwrap();
+
+        assert_eq!(app.mode(), &AppMode::Normal);
+        let config = app.config_manager.read().await;
+        assert_eq!(config.global.ui.theme, "dark");
+    }
+}
@@ -2,6 +2,6 @@ pub mod settings;
 pub mod workspace_manager;
 pub mod workspace_picker;
 
-pub use settings::SettingsModal;
+pub use settings::{SettingsField, SettingsModal};
 pub use workspace_manager::WorkspaceManager;
 pub use workspace_picker::WorkspacePicker;
@@ -1,21 +1,352 @@
-use crate::config::{
-    ConfigManager, DatabaseConfig, LlmConfig, SessionConfig, ShortcutsConfig, UiConfig,
-};
+use crate::config::{ConfigManager, GlobalConfig, WorkspaceConfig, BUILTIN_THEMES};
 use crate::tui::{App, Layout, SettingsTab};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap};
-use std::sync::Arc;
-use tokio::sync::RwLock;
+
+const LAYOUTS: [&str; 3] = ["compact", "comfortable", "spacious"];
+
+/// Editable fields shown in the settings modal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsField {
+    Theme,
+    Layout,
+    Colored,
+    ShowLineNumbers,
+    WordWrap,
+    AnimationDuration,
+    MaxObjectives,
+    AutoSave,
+    BackupFrequency,
+    WorkspaceScanDepth,
+    MaxIterations,
+    Temperature,
+    RequestTimeout,
+    EnableStreaming,
+    ReadOnly,
+    QueryTimeout,
+    QueryResultLimit,
+    EnableCaching,
+}
+
+impl SettingsField {
+    /// Fields editable on a settings tab, in display order
+    ///
+    /// Shortcuts are free-form key strings and are shown read-only.
+    pub fn for_tab(tab: &SettingsTab) -> &'static [Self] {
+        match tab {
+            SettingsTab::Ui => &[
+                Self::Theme,
+                Self::Layout,
+                Self::Colored,
+                Self::ShowLineNumbers,
+                Self::WordWrap,
+                Self::AnimationDuration,
+            ],
+            SettingsTab::Session => &[
+                Self::MaxObjectives,
+                Self::AutoSave,
+                Self::BackupFrequency,
+                Self::WorkspaceScanDepth,
+            ],
+            SettingsTab::Llm => &[
+                Self::MaxIterations,
+                Self::Temperature,
+                Self::RequestTimeout,
+                Self::EnableStreaming,
+            ],
+            SettingsTab::Database => &[
+                Self::ReadOnly,
+                Self::QueryTimeout,
+                Self::QueryResultLimit,
+                Self::EnableCaching,
+            ],
+            SettingsTab::Shortcuts => &[],
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Theme => "Theme",
+            Self::Layout => "Layout",
+            Self::Colored => "Colored Output",
+            Self::ShowLineNumbers => "Show Line Numbers",
+            Self::WordWrap => "Word Wrap",
+            Self::AnimationDuration => "Animation Duration",
+            Self::MaxObjectives => "Max Objectives",
+            Self::AutoSave => "Auto-save",
+            Self::BackupFrequency => "Backup Frequency",
+            Self::WorkspaceScanDepth => "Workspace Scan Depth",
+            Self::MaxIterations => "Max Iterations",
+            Self::Temperature => "Temperature",
+            Self::RequestTimeout => "Request Timeout",
+            Self::EnableStreaming => "Streaming",
+            Self::ReadOnly => "Read-only Mode",
+            Self::QueryTimeout => "Query Timeout",
+            Self::QueryResultLimit => "Query Result Limit",
+            Self::EnableCaching => "Cache Results",
+        }
+    }
+
+    /// Display value of the field in an effective configuration
+    pub fn value(&self, config: &GlobalConfig) -> String {
+        match self {
+            Self::Theme => config.ui.theme.clone(),
+            Self::Layout => config.ui.layout.clone(),
+            Self::Colored => checkbox(config.ui.colored),
+            Self::ShowLineNumbers => checkbox(config.ui.show_line_numbers),
+            Self::WordWrap => checkbox(config.ui.word_wrap),
+            Self::AnimationDuration => format!("{}ms", config.ui.animation_duration),
+            Self::MaxObjectives => config.session.max_objectives.to_string(),
+            Self::AutoSave => checkbox(config.session.auto_save),
+            Self::BackupFrequency => format!("{} minutes", config.session.backup_frequency),
+            Self::WorkspaceScanDepth => format!("{} levels", config.session.workspace_scan_depth),
+            Self::MaxIterations => config.llm.max_iterations.to_string(),
+            Self::Temperature => format!("{:.1}", config.llm.temperature),
+            Self::RequestTimeout => format!("{} seconds", config.llm.request_timeout),
+            Self::EnableStreaming => checkbox(config.llm.enable_streaming),
+            Self::ReadOnly => checkbox(config.database.read_only),
+            Self::QueryTimeout => format!("{} seconds", config.database.query_timeout),
+            Self::QueryResultLimit => format!("{} rows", config.database.query_result_limit),
+            Self::EnableCaching => checkbox(config.database.enable_caching),
+        }
+    }
+
+    /// Step the field to its next (or previous) value
+    ///
+    /// A field the workspace already overrides is changed in the workspace
+    /// configuration; anything else is changed globally. The result is not
+    /// validated here.
+    pub fn adjust(&self, manager: &mut ConfigManager, forward: bool) {
+        let mut next = manager.effective_config();
+        self.step(&mut next, forward);
+        self.assign(manager, &next);
+    }
+
+    /// Step the field's value within an effective configuration
+    fn step(&self, config: &mut GlobalConfig, forward: bool) {
+        match self {
+            Self::Theme => {
+                let mut themes: Vec<String> =
+                    BUILTIN_THEMES.iter().map(|name| name.to_string()).collect();
+                let mut custom: Vec<String> = config.ui.themes.keys().cloned().collect();
+                custom.sort();
+                themes.extend(custom);
+
+                config.ui.theme = cycle(&themes, &config.ui.theme, forward);
+            }
+            Self::Layout => {
+                let layouts: Vec<String> = LAYOUTS.iter().map(|name| name.to_string()).collect();
+                config.ui.layout = cycle(&layouts, &config.ui.layout, forward);
+            }
+            Self::Colored => config.ui.colored = !config.ui.colored,
+            Self::ShowLineNumbers => config.ui.show_line_numbers = !config.ui.show_line_numbers,
+            Self::WordWrap => config.ui.word_wrap = !config.ui.word_wrap,
+            Self::AnimationDuration => {
+                config.ui.animation_duration = step(config.ui.animation_duration, 50, forward)
+            }
+            Self::MaxObjectives => {
+                config.session.max_objectives =
+                    step(config.session.max_objectives as u64, 1, forward) as usize
+            }
+            Self::AutoSave => config.session.auto_save = !config.session.auto_save,
+            Self::BackupFrequency => {
+                config.session.backup_frequency =
+                    step(config.session.backup_frequency as u64, 5, forward) as u32
+            }
+            Self::WorkspaceScanDepth => {
+                config.session.workspace_scan_depth =
+                    step(config.session.workspace_scan_depth as u64, 1, forward) as usize
+            }
+            Self::MaxIterations => {
+                config.llm.max_iterations =
+                    step(config.llm.max_iterations as u64, 1, forward) as u32
+            }
+            Self::Temperature => {
+                // Step in tenths so repeated presses don't accumulate float error
+                let tenths = (config.llm.temperature * 10.0).round() as u64;
+                config.llm.temperature = step(tenths, 1, forward) as f32 / 10.0;
+            }
+            Self::RequestTimeout => {
+                config.llm.request_timeout = step(config.llm.request_timeout, 5, forward)
+            }
+            Self::EnableStreaming => config.llm.enable_streaming = !config.llm.enable_streaming,
+            Self::ReadOnly => config.database.read_only = !config.database.read_only,
+            Self::QueryTimeout => {
+                config.database.query_timeout = step(config.database.query_timeout, 5, forward)
+            }
+            Self::QueryResultLimit => {
+                config.database.query_result_limit =
+                    step(config.database.query_result_limit as u64, 100, forward) as usize
+            }
+            Self::EnableCaching => config.database.enable_caching = !config.database.enable_caching,
+        }
+    }
+
+    /// Copy the field's value from an effective configuration into `manager`
+    ///
+    /// Also used to replay edits onto the configuration as stored on disk,
+    /// so a save writes only the fields the user changed.
+    pub fn assign(&self, manager: &mut ConfigManager, source: &GlobalConfig) {
+        match self {
+            Self::Theme => set_field(
+                manager,
+                source.ui.theme.clone(),
+                |g| &mut g.ui.theme,
+                |w| w.ui.as_mut().map(|o| &mut o.theme),
+            ),
+            Self::Layout => set_field(
+                manager,
+                source.ui.layout.clone(),
+                |g| &mut g.ui.layout,
+                |w| w.ui.as_mut().map(|o| &mut o.layout),
+            ),
+            Self::Colored => set_field(
+                manager,
+                source.ui.colored,
+                |g| &mut g.ui.colored,
+                |w| w.ui.as_mut().map(|o| &mut o.colored),
+            ),
+            Self::ShowLineNumbers => set_field(
+                manager,
+                source.ui.show_line_numbers,
+                |g| &mut g.ui.show_line_numbers,
+                |w| w.ui.as_mut().map(|o| &mut o.show_line_numbers),
+            ),
+            Self::WordWrap => set_field(
+                manager,
+                source.ui.word_wrap,
+                |g| &mut g.ui.word_wrap,
+                |w| w.ui.as_mut().map(|o| &mut o.word_wrap),
+            ),
+            Self::AnimationDuration => set_field(
+                manager,
+                source.ui.animation_duration,
+                |g| &mut g.ui.animation_duration,
+                |w| w.ui.as_mut().map(|o| &mut o.animation_duration),
+            ),
+            Self::MaxObjectives => set_field(
+                manager,
+                source.session.max_objectives,
+                |g| &mut g.session.max_objectives,
+                |w| w.session.as_mut().map(|o| &mut o.max_objectives),
+            ),
+            Self::AutoSave => set_field(
+                manager,
+                source.session.auto_save,
+                |g| &mut g.session.auto_save,
+                |w| w.session.as_mut().map(|o| &mut o.auto_save),
+            ),
+            Self::BackupFrequency => set_field(
+                manager,
+                source.session.backup_frequency,
+                |g| &mut g.session.backup_frequency,
+                |w| w.session.as_mut().map(|o| &mut o.backup_frequency),
+            ),
+            Self::WorkspaceScanDepth => set_field(
+                manager,
+                source.session.workspace_scan_depth,
+                |g| &mut g.session.workspace_scan_depth,
+                |w| w.session.as_mut().map(|o| &mut o.workspace_scan_depth),
+            ),
+            Self::MaxIterations => set_field(
+                manager,
+                source.llm.max_iterations,
+                |g| &mut g.llm.max_iterations,
+                |w| w.llm.as_mut().map(|o| &mut o.max_iterations),
+            ),
+            Self::Temperature => set_field(
+                manager,
+                source.llm.temperature,
+                |g| &mut g.llm.temperature,
+                |w| w.llm.as_mut().map(|o| &mut o.temperature),
+            ),
+            Self::RequestTimeout => set_field(
+                manager,
+                source.llm.request_timeout,
+                |g| &mut g.llm.request_timeout,
+                |w| w.llm.as_mut().map(|o| &mut o.request_timeout),
+            ),
+            Self::EnableStreaming => set_field(
+                manager,
+                source.llm.enable_streaming,
+                |g| &mut g.llm.enable_streaming,
+                |w| w.llm.as_mut().map(|o| &mut o.enable_streaming),
+            ),
+            Self::ReadOnly => set_field(
+                manager,
+                source.database.read_only,
+                |g| &mut g.database.read_only,
+                |w| w.database.as_mut().map(|o| &mut o.read_only),
+            ),
+            Self::QueryTimeout => set_field(
+                manager,
+                source.database.query_timeout,
+                |g| &mut g.database.query_timeout,
+                |w| w.database.as_mut().map(|o| &mut o.query_timeout),
+            ),
+            Self::QueryResultLimit => set_field(
+                manager,
+                source.database.query_result_limit,
+                |g| &mut g.database.query_result_limit,
+                |w| w.database.as_mut().map(|o| &mut o.query_result_limit),
+            ),
+            Self::EnableCaching => set_field(
+                manager,
+                source.database.enable_caching,
+                |g| &mut g.database.enable_caching,
+                |w| w.database.as_mut().map(|o| &mut o.enable_caching),
+            ),
+        }
+    }
+}
+
+/// Write a value to the workspace override when one is set, otherwise globally
+fn set_field<T>(
+    manager: &mut ConfigManager,
+    value: T,
+    global: impl FnOnce(&mut GlobalConfig) -> &mut T,
+    workspace: impl FnOnce(&mut WorkspaceConfig) -> Option<&mut Option<T>>,
+) {
+    let slot = manager
+        .workspace
+        .as_mut()
+        .and_then(workspace)
+        .filter(|slot| slot.is_some());
+
+    match slot {
+        Some(slot) => *slot = Some(value),
+        None => *global(&mut manager.global) = value,
+    }
+}
+
+fn cycle(options: &[String], current: &str, forward: bool) -> String {
+    let len = options.len();
+    let index = options.iter().position(|option| option == current);
+    let next = match (index, forward) {
+        (Some(i), true) => (i + 1) % len,
+        (Some(i), false) => (i + len - 1) % len,
+        (None, _) => 0,
+    };
+    options[next].clone()
+}
+
+fn step(value: u64, amount: u64, forward: bool) -> u64 {
+    if forward {
+        value.saturating_add(amount)
+    } else {
+        value.saturating_sub(amount)
+    }
+}
+
+fn checkbox(value: bool) -> String {
+    if value { "☑" } else { "☐" }.to_string()
+}
 
 pub struct SettingsModal;
 
 impl SettingsModal {
-    pub fn render(
-        frame: &mut Frame<'_>,
-        app: &App,
-        config_manager: Arc<RwLock<ConfigManager>>,
-        area: Rect,
-    ) {
+    /// Render the settings modal from the app's unsaved settings draft
+    pub fn render(frame: &mut Frame<'_>, app: &App, area: Rect) {
         let settings_chunks = Layout::settings_layout(area);
 
         // Clear the background
@@ -25,28 +356,25 @@ impl SettingsModal {
         let settings_block = Block::default()
             .title("Settings (Ctrl+, to toggle)")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Blue));
+            .border_style(Style::default().fg(app.theme().accent));
         frame.render_widget(settings_block, Layout::centered_rect(80, 70, area));
 
         // Render tabs
         Self::render_tabs(frame, app, settings_chunks[0]);
 
         // Render content based on selected tab
-        tokio::spawn(async move {
-            let _config = config_manager.read().await;
-            // Note: In a real implementation, we'd need to structure this differently
-            // to avoid async in sync context. For now, this shows the structure.
-        });
-
-        // For now, render placeholder content
-        Self::render_placeholder_content(frame, app, settings_chunks[1]);
+        if let Some(draft) = app.settings_draft() {
+            let config = draft.effective_config();
+            Self::render_content(frame, app, &config, settings_chunks[1]);
+        }
 
         // Render action buttons
         Self::render_actions(frame, app, settings_chunks[2]);
     }
 
     fn render_tabs(frame: &mut Frame<'_>, app: &App, area: Rect) {
-        let tab_titles: Vec<&str> = vec!["UI", "Session", "LLM", "Database", "Shortcuts"];
+        let theme = app.theme();
+        let tab_titles = ["UI", "Session", "LLM", "Database", "Shortcuts"];
         let selected_tab = match app.settings_tab() {
             SettingsTab::Ui => 0,
             SettingsTab::Session => 1,
@@ -57,139 +385,90 @@ impl SettingsModal {
 
         let tabs = Tabs::new(tab_titles)
             .block(Block::default().borders(Borders::BOTTOM))
-            .style(Style::default().fg(Color::Gray))
-            .highlight_style(Style::default().fg(Color::Yellow).bold())
+            .style(Style::default().fg(theme.muted))
+            .highlight_style(Style::default().fg(theme.highlight).bold())
             .select(selected_tab);
 
         frame.render_widget(tabs, area);
     }
 
-    fn render_placeholder_content(frame: &mut Frame<'_>, app: &App, area: Rect) {
-        let content = match app.settings_tab() {
-            SettingsTab::Ui => Self::render_ui_settings_placeholder(area),
-            SettingsTab::Session => Self::render_session_settings_placeholder(area),
-            SettingsTab::Llm => Self::render_llm_settings_placeholder(area),
-            SettingsTab::Database => Self::render_database_settings_placeholder(area),
-            SettingsTab::Shortcuts => Self::render_shortcuts_settings_placeholder(area),
+    fn render_content(frame: &mut Frame<'_>, app: &App, config: &GlobalConfig, area: Rect) {
+        let theme = app.theme();
+        let tab = app.settings_tab();
+
+        let mut lines: Vec<Line> = match tab {
+            SettingsTab::Shortcuts => config
+                .shortcuts
+                .bindings()
+                .iter()
+                .map(|(name, key)| {
+                    Line::from(vec![
+                        Span::styled(format!("{:<20}", name), Style::default().fg(theme.accent)),
+                        Span::styled(key.to_string(), Style::default().fg(theme.foreground)),
+                    ])
+                })
+                .collect(),
+            _ => SettingsField::for_tab(tab)
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let line = Line::from(vec![
+                        Span::styled(
+                            format!("{:<22}", field.label()),
+                            Style::default().fg(theme.accent),
+                        ),
+                        Span::styled(field.value(config), Style::default().fg(theme.foreground)),
+                    ]);
+
+                    if i == app.settings_field() {
+                        line.style(Style::default().bg(theme.selection))
+                    } else {
+                        line
+                    }
+                })
+                .collect(),
         };
 
-        frame.render_widget(content, area);
-    }
-
-    fn render_ui_settings_placeholder(_area: Rect) -> Paragraph<'static> {
-        let text = vec![
-            Line::from("Theme Settings:"),
-            Line::from(""),
-            Line::from("● Dark   ○ Light   ○ Auto"),
-            Line::from(""),
-            Line::from("Layout:"),
-            Line::from("○ Compact   ● Comfortable   ○ Spacious"),
-            Line::from(""),
-            Line::from("Animation Speed: [████████──] 200ms"),
-            Line::from("Show Line Numbers: ☑"),
-            Line::from("Word Wrap: ☑"),
-        ];
-
-        Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("UI Settings"))
-            .wrap(Wrap { trim: true })
-    }
-
-    fn render_session_settings_placeholder(_area: Rect) -> Paragraph<'static> {
-        let text = vec![
-            Line::from("Chat History: 1000 messages"),
-            Line::from("Auto-save: Every 30 seconds"),
-            Line::from("Backup Count: 5 files"),
-            Line::from(""),
-            Line::from("Workspace Settings:"),
-            Line::from("Auto-detect workspace: ☑"),
-            Line::from("Load last workspace: ☑"),
-        ];
-
-        Paragraph::new(text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Session Settings"),
-            )
-            .wrap(Wrap { trim: true })
-    }
-
-    fn render_llm_settings_placeholder(_area: Rect) -> Paragraph<'static> {
-        let text = vec![
-            Line::from("Default Model: gpt-3.5-turbo"),
-            Line::from("Max Iterations: 10"),
-            Line::from("Temperature: 0.7"),
-            Line::from(""),
-            Line::from("Provider Settings:"),
-            Line::from("OpenAI API Key: [Set]"),
-            Line::from("Streaming: ☑"),
-            Line::from("Timeout: 30 seconds"),
-        ];
-
-        Paragraph::new(text)
-            .block(Block::default().borders(Borders::ALL).title("LLM Settings"))
-            .wrap(Wrap { trim: true })
-    }
-
-    fn render_database_settings_placeholder(_area: Rect) -> Paragraph<'static> {
-        let text = vec![
-            Line::from("Connection Settings:"),
-            Line::from("Read-only Mode: ☑"),
-            Line::from("Query Timeout: 30 seconds"),
-            Line::from("Max Query Time: 300 seconds"),
-            Line::from(""),
-            Line::from("Performance:"),
-            Line::from("Cache Results: ☑"),
-            Line::from("Max Cache Size: 100 MB"),
-        ];
+        lines.push(Line::from(""));
+        if let Some(error) = app.settings_error() {
+            lines.push(Line::styled(
+                error.to_string(),
+                Style::default().fg(theme.error),
+            ));
+        }
+        let hint = match tab {
+            SettingsTab::Shortcuts => "Edit shortcuts in the configuration file • Esc: Close",
+            _ => "↑/↓: Select • ←/→/Enter: Change • s: Save • r: Reset tab • Esc: Discard",
+        };
+        lines.push(Line::styled(hint, Style::default().fg(theme.muted)));
 
-        Paragraph::new(text)
+        let content = Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Database Settings"),
+                    .title(format!("{} Settings", tab.title())),
             )
-            .wrap(Wrap { trim: true })
-    }
+            .wrap(Wrap { trim: true });
 
-    fn render_shortcuts_settings_placeholder(_area: Rect) -> Paragraph<'static> {
-        let text = vec![
-            Line::from("Navigation:"),
-            Line::from("Quit:           Ctrl+Q"),
-            Line::from("Settings:       Ctrl+,"),
-            Line::from("New Objective:  Ctrl+N"),
-            Line::from("Delete Obj:     Ctrl+D"),
-            Line::from(""),
-            Line::from("Interface:"),
-            Line::from("Toggle History: Ctrl+H"),
-            Line::from("Save Session:   Ctrl+S"),
-        ];
-
-        Paragraph::new(text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Keyboard Shortcuts"),
-            )
-            .wrap(Wrap { trim: true })
+        frame.render_widget(content, area);
     }
 
     fn render_actions(frame: &mut Frame<'_>, app: &App, area: Rect) {
         let action_chunks = Layout::settings_actions_layout(area);
+        let theme = app.theme();
 
         let apply_style = if app.settings_modified() {
-            Style::default().fg(Color::Green).bold()
+            Style::default().fg(theme.success).bold()
         } else {
-            Style::default().fg(Color::Gray)
+            Style::default().fg(theme.muted)
         };
 
-        let actions = vec![
+        let actions = [
             ("Apply", apply_style),
-            ("Reset", Style::default().fg(Color::Red)),
-            ("Export", Style::default().fg(Color::Blue)),
-            ("Import", Style::default().fg(Color::Blue)),
-            ("Close", Style::default().fg(Color::White)),
+            ("Reset", Style::default().fg(theme.error)),
+            ("Export", Style::default().fg(theme.accent)),
+            ("Import", Style::default().fg(theme.accent)),
+            ("Close", Style::default().fg(theme.foreground)),
         ];
 
         for (i, (text, style)) in actions.iter().enumerate() {
@@ -202,69 +481,57 @@ impl SettingsModal {
             }
         }
     }
+}
 
-    // Placeholder for future implementation
-    pub async fn render_with_config(
-        frame: &mut Frame<'_>,
-        app: &App,
-        config_manager: Arc<RwLock<ConfigManager>>,
-        area: Rect,
-    ) {
-        let config = config_manager.read().await;
-
-        match app.settings_tab() {
-            SettingsTab::Ui => {
-                let ui_config = config.effective_ui_config();
-                Self::render_ui_settings_with_config(frame, &ui_config, area);
-            }
-            SettingsTab::Session => {
-                let session_config = config.effective_session_config();
-                Self::render_session_settings_with_config(frame, &session_config, area);
-            }
-            SettingsTab::Llm => {
-                let llm_config = config.effective_llm_config();
-                Self::render_llm_settings_with_config(frame, &llm_config, area);
-            }
-            SettingsTab::Database => {
-                let db_config = config.effective_database_config();
-                Self::render_database_settings_with_config(frame, &db_config, area);
-            }
-            SettingsTab::Shortcuts => {
-                let shortcuts_config = config.effective_shortcuts_config();
-                Self::render_shortcuts_settings_with_config(frame, &shortcuts_config, area);
-            }
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::UiOverrides;
 
-    fn render_ui_settings_with_config(_frame: &mut Frame<'_>, _config: &UiConfig, _area: Rect) {
-        // TODO: Implement actual UI settings rendering with real config values
-    }
+    #[test]
+    fn test_theme_cycles_through_builtin_themes() {
+        let mut manager = ConfigManager::new().unwrap();
+        assert_eq!(manager.global.ui.theme, "dark");
 
-    fn render_session_settings_with_config(
-        _frame: &mut Frame<'_>,
-        _config: &SessionConfig,
-        _area: Rect,
-    ) {
-        // TODO: Implement actual session settings rendering with real config values
-    }
+        SettingsField::Theme.adjust(&mut manager, true);
+        assert_eq!(manager.global.ui.theme, "light");
 
-    fn render_llm_settings_with_config(_frame: &mut Frame<'_>, _config: &LlmConfig, _area: Rect) {
-        // TODO: Implement actual LLM settings rendering with real config values
+        SettingsField::Theme.adjust(&mut manager, false);
+        SettingsField::Theme.adjust(&mut manager, false);
+        assert_eq!(manager.global.ui.theme, "auto");
     }
 
-    fn render_database_settings_with_config(
-        _frame: &mut Frame<'_>,
-        _config: &DatabaseConfig,
-        _area: Rect,
-    ) {
-        // TODO: Implement actual database settings rendering with real config values
+    #[test]
+    fn test_adjust_writes_workspace_override_when_set() {
+        let mut manager = ConfigManager::new().unwrap();
+        manager.workspace = Some(WorkspaceConfig {
+            ui: Some(UiOverrides {
+                theme: Some("light".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        SettingsField::Theme.adjust(&mut manager, true);
+        SettingsField::WordWrap.adjust(&mut manager, true);
+
+        let overrides = manager.workspace.as_ref().unwrap().ui.as_ref().unwrap();
+        assert_eq!(overrides.theme.as_deref(), Some("auto"));
+        assert_eq!(overrides.word_wrap, None);
+        assert_eq!(manager.global.ui.theme, "dark");
+        assert!(!manager.global.ui.word_wrap);
     }
 
-    fn render_shortcuts_settings_with_config(
-        _frame: &mut Frame<'_>,
-        _config: &ShortcutsConfig,
-        _area: Rect,
-    ) {
-        // TODO: Implement actual shortcuts settings rendering with real config values
+    #[test]
+    fn test_temperature_steps_in_tenths() {
+        let mut manager = ConfigManager::new().unwrap();
+        manager.global.llm.temperature = 0.7;
+
+        SettingsField::Temperature.adjust(&mut manager, true);
+        assert_eq!(manager.global.llm.temperature, 0.8);
+
+        manager.global.llm.temperature = 0.0;
+        SettingsField::Temperature.adjust(&mut manager, false);
+        assert_eq!(manager.global.llm.temperature, 0.0);
     }
 }
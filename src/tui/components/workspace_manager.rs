@@ -1,9 +1,16 @@
 use super::workspace_picker::WorkspaceInfo;
 use crate::config::WorkspaceConfig;
+use crate::tui::Theme;
 use crate::{ErrorContext, PixlieError, Result};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+use unicode_width::UnicodeWidthChar;
+
+/// Number of entries remembered per wizard field
+const CREATE_HISTORY_LIMIT: usize = 20;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum WorkspaceManagerMode {
@@ -14,6 +21,14 @@ pub enum WorkspaceManagerMode {
     Delete,
 }
 
+/// Text field being edited in the create-workspace wizard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CreateField {
+    Name,
+    Path,
+    Description,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum WorkspaceAction {
     Open,
@@ -32,6 +47,8 @@ pub struct WorkspaceManager {
     mode: WorkspaceManagerMode,
     current_workspace: Option<WorkspaceInfo>,
     action: Option<WorkspaceAction>,
+    theme: Theme,
+    scan_depth: usize,
 
     // Edit state
     edit_name: String,
@@ -43,6 +60,18 @@ pub struct WorkspaceManager {
     create_path: String,
     create_description: String,
     create_step: usize, // 0-4 for the 5 steps in the wizard
+    create_field: CreateField,
+    create_cursor: usize, // Cursor position in characters within the active field
+    create_error: Option<String>,
+    create_history: HashMap<CreateField, Vec<String>>,
+    create_history_index: Option<usize>, // Entry recalled into the active field
+    create_history_draft: String,        // Value typed before recalling history
+}
+
+impl Default for WorkspaceManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl WorkspaceManager {
@@ -56,6 +85,8 @@ impl WorkspaceManager {
             mode: WorkspaceManagerMode::List,
             current_workspace: None,
             action: None,
+            theme: Theme::default(),
+            scan_depth: 2,
             edit_name: String::new(),
             edit_description: String::new(),
             edit_path: String::new(),
@@ -63,15 +94,19 @@ impl WorkspaceManager {
             create_path: String::new(),
             create_description: String::new(),
             create_step: 0,
+            create_field: CreateField::Name,
+            create_cursor: 0,
+            create_error: None,
+            create_history: HashMap::new(),
+            create_history_index: None,
+            create_history_draft: String::new(),
         }
     }
 
     pub async fn load_workspaces(&mut self) -> Result<()> {
         let context = ErrorContext::new().with_context("Loading workspaces for manager");
 
-        let mut workspaces = Vec::new();
-
-        // Check current directory and subdirectories
+        // Check current directory and user's home directory
         let current_dir = std::env::current_dir().map_err(|e| {
             PixlieError::session(
                 format!("Failed to get current directory: {}", e),
@@ -79,53 +114,82 @@ impl WorkspaceManager {
             )
         })?;
 
-        self.scan_directory_for_workspaces(&current_dir, &mut workspaces)
-            .await?;
+        let mut roots = vec![current_dir];
+        roots.extend(dirs::home_dir());
 
-        // Check user's home directory
-        if let Some(home_dir) = dirs::home_dir() {
-            self.scan_directory_for_workspaces(&home_dir, &mut workspaces)
-                .await?;
-        }
-
-        // Sort by name
-        workspaces.sort_by(|a, b| a.name.cmp(&b.name));
-
-        self.workspaces = workspaces;
+        self.workspaces = self.scan_roots(&roots).await?;
 
         // Select first workspace if available
-        if !self.workspaces.is_empty() {
+        if self.workspaces.is_empty() {
+            self.selected.select(None);
+        } else {
             self.selected.select(Some(0));
         }
 
         Ok(())
     }
 
+    /// Scan each root for workspaces, dropping duplicates found via several roots
+    async fn scan_roots(&self, roots: &[PathBuf]) -> Result<Vec<WorkspaceInfo>> {
+        let mut workspaces = Vec::new();
+        for root in roots {
+            self.scan_directory_for_workspaces(root, &mut workspaces)
+                .await?;
+        }
+
+        // The same workspace can be reached from overlapping roots (e.g. the
+        // home directory under the current one) or through symlinks
+        let mut seen = HashSet::new();
+        workspaces.retain(|workspace| {
+            seen.insert(std::fs::canonicalize(&workspace.path).unwrap_or(workspace.path.clone()))
+        });
+
+        // Sort by name
+        workspaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(workspaces)
+    }
+
+    /// Collect workspaces in `dir` and up to `scan_depth` levels below it
+    ///
+    /// Hidden directories are skipped, as are directories that can't be read.
     async fn scan_directory_for_workspaces(
         &self,
-        dir: &PathBuf,
+        dir: &Path,
         workspaces: &mut Vec<WorkspaceInfo>,
     ) -> Result<()> {
-        let workspace_file = dir.join(".pixlie-workspace.toml");
+        let mut pending = vec![(dir.to_path_buf(), 0)];
 
-        if workspace_file.exists() {
-            if let Ok(workspace_info) = self.load_workspace_info(&workspace_file).await {
-                workspaces.push(workspace_info);
+        while let Some((dir, depth)) = pending.pop() {
+            let workspace_file = dir.join(".pixlie-workspace.toml");
+            if workspace_file.exists() {
+                if let Ok(workspace_info) = self.load_workspace_info(&workspace_file).await {
+                    workspaces.push(workspace_info);
+                }
             }
-        }
 
-        // Scan subdirectories
-        if let Ok(entries) = std::fs::read_dir(dir) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_dir() && !path.starts_with(".") {
-                    let workspace_file = path.join(".pixlie-workspace.toml");
-                    if workspace_file.exists() {
-                        if let Ok(workspace_info) = self.load_workspace_info(&workspace_file).await
-                        {
-                            workspaces.push(workspace_info);
-                        }
-                    }
+            if depth >= self.scan_depth {
+                continue;
+            }
+
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) => {
+                    debug!("Skipping unreadable directory {:?}: {}", dir, e);
+                    continue;
+                }
+            };
+
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+                let is_dir = entry
+                    .file_type()
+                    .await
+                    .map(|file_type| file_type.is_dir())
+                    .unwrap_or(false);
+
+                if is_dir && !is_hidden {
+                    pending.push((entry.path(), depth + 1));
                 }
             }
         }
@@ -133,6 +197,11 @@ impl WorkspaceManager {
         Ok(())
     }
 
+    /// Set how many directory levels below each root are searched
+    pub fn set_scan_depth(&mut self, depth: usize) {
+        self.scan_depth = depth;
+    }
+
     async fn load_workspace_info(&self, config_path: &PathBuf) -> Result<WorkspaceInfo> {
         let content = tokio::fs::read_to_string(config_path).await.map_err(|e| {
             PixlieError::session(
@@ -170,12 +239,20 @@ impl WorkspaceManager {
         self.current_workspace = Some(workspace);
     }
 
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     pub fn next(&mut self) {
         let len = match self.mode {
             WorkspaceManagerMode::List => self.workspaces.len(),
             _ => return,
         };
 
+        if len == 0 {
+            return;
+        }
+
         let i = match self.selected.selected() {
             Some(i) => {
                 if i >= len - 1 {
@@ -195,6 +272,10 @@ impl WorkspaceManager {
             _ => return,
         };
 
+        if len == 0 {
+            return;
+        }
+
         let i = match self.selected.selected() {
             Some(i) => {
                 if i == 0 {
@@ -231,17 +312,22 @@ impl WorkspaceManager {
         if let Ok(current_dir) = std::env::current_dir() {
             self.create_path = current_dir.to_string_lossy().to_string();
         }
+
+        self.create_error = None;
+        self.focus_create_field(CreateField::Name);
     }
 
     pub fn enter_edit_mode(&mut self) {
         let workspace_info = if let Some(workspace) = self.selected_workspace() {
-            (workspace.name.clone(), 
-             workspace.description.clone().unwrap_or_default(),
-             workspace.path.to_string_lossy().to_string())
+            (
+                workspace.name.clone(),
+                workspace.description.clone().unwrap_or_default(),
+                workspace.path.to_string_lossy().to_string(),
+            )
         } else {
             return;
         };
-        
+
         self.mode = WorkspaceManagerMode::Edit;
         self.edit_name = workspace_info.0;
         self.edit_description = workspace_info.1;
@@ -258,16 +344,307 @@ impl WorkspaceManager {
         self.mode = WorkspaceManagerMode::List;
     }
 
+    /// Whether the selected workspace is the one currently open in the app
+    pub fn is_selected_current(&self) -> bool {
+        match (self.selected_workspace(), &self.current_workspace) {
+            (Some(selected), Some(current)) => selected.path == current.path,
+            _ => false,
+        }
+    }
+
+    /// Delete the selected workspace's configuration file and return to list mode
+    ///
+    /// Only `.pixlie-workspace.toml` is removed; data files in the workspace
+    /// directory are left untouched. Returns the deleted workspace so the
+    /// caller can unload it if it was the currently open one.
+    pub async fn delete_selected_workspace(&mut self) -> Result<Option<WorkspaceInfo>> {
+        let index = match self.selected.selected() {
+            Some(index) if index < self.workspaces.len() => index,
+            _ => {
+                self.return_to_list();
+                return Ok(None);
+            }
+        };
+
+        let workspace_file = self.workspaces[index].path.join(".pixlie-workspace.toml");
+
+        tokio::fs::remove_file(&workspace_file).await.map_err(|e| {
+            PixlieError::session(
+                format!(
+                    "Failed to delete workspace config {}: {}",
+                    workspace_file.display(),
+                    e
+                ),
+                ErrorContext::new().with_context("Workspace deletion"),
+            )
+        })?;
+
+        let deleted = self.workspaces.remove(index);
+
+        if self
+            .current_workspace
+            .as_ref()
+            .is_some_and(|current| current.path == deleted.path)
+        {
+            self.current_workspace = None;
+        }
+
+        // Keep the selection within bounds of the shortened list
+        if self.workspaces.is_empty() {
+            self.selected.select(None);
+        } else if index >= self.workspaces.len() {
+            self.selected.select(Some(self.workspaces.len() - 1));
+        }
+
+        self.return_to_list();
+        Ok(Some(deleted))
+    }
+
+    /// Advance the wizard, validating the location when leaving step 1
     pub fn next_create_step(&mut self) {
+        if self.create_step == 0 {
+            if let Err(e) = self.validate_create_location() {
+                self.create_error = Some(e.user_message());
+                return;
+            }
+        }
+
+        self.record_create_history();
         if self.create_step < 4 {
             self.create_step += 1;
+            self.create_error = None;
+            self.focus_step_field();
         }
     }
 
     pub fn previous_create_step(&mut self) {
         if self.create_step > 0 {
             self.create_step -= 1;
+            self.create_error = None;
+            self.focus_step_field();
+        }
+    }
+
+    /// Whether the current wizard step has an editable text field
+    pub fn create_step_has_input(&self) -> bool {
+        self.create_step <= 1
+    }
+
+    /// Move between the Name and Path fields on the first wizard step
+    pub fn next_create_field(&mut self) {
+        match self.create_field {
+            CreateField::Name => self.focus_create_field(CreateField::Path),
+            CreateField::Path => self.focus_create_field(CreateField::Name),
+            CreateField::Description => {}
+        }
+    }
+
+    /// Replace the active field with the previous value entered in it
+    pub fn create_history_previous(&mut self) {
+        let len = self
+            .create_history
+            .get(&self.create_field)
+            .map_or(0, Vec::len);
+        let index = match self.create_history_index {
+            _ if len == 0 => return,
+            None => {
+                self.create_history_draft = self.create_field_value().to_string();
+                len - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+        self.recall_create_history(Some(index));
+    }
+
+    /// Step forward through the active field's history, ending at the draft
+    pub fn create_history_next(&mut self) {
+        let len = self
+            .create_history
+            .get(&self.create_field)
+            .map_or(0, Vec::len);
+        match self.create_history_index {
+            Some(index) if index + 1 < len => self.recall_create_history(Some(index + 1)),
+            Some(_) => self.recall_create_history(None),
+            None => {}
+        }
+    }
+
+    fn recall_create_history(&mut self, index: Option<usize>) {
+        let value = match index {
+            Some(index) => self.create_history[&self.create_field][index].clone(),
+            None => std::mem::take(&mut self.create_history_draft),
+        };
+        *self.create_field_value_mut() = value;
+        self.create_cursor = self.create_field_value().chars().count();
+        self.create_history_index = index;
+        self.create_error = None;
+    }
+
+    /// Remember the values accepted on the current step for later recall
+    fn record_create_history(&mut self) {
+        let fields: &[CreateField] = match self.create_step {
+            0 => &[CreateField::Name, CreateField::Path],
+            1 => &[CreateField::Description],
+            _ => &[],
+        };
+
+        for &field in fields {
+            let value = self.field_value(field).trim().to_string();
+            if value.is_empty() {
+                continue;
+            }
+
+            let history = self.create_history.entry(field).or_default();
+            history.retain(|entry| *entry != value);
+            history.push(value);
+            if history.len() > CREATE_HISTORY_LIMIT {
+                history.remove(0);
+            }
+        }
+    }
+
+    /// Insert a character at the cursor in the active field
+    pub fn create_input_char(&mut self, c: char) {
+        self.create_history_index = None;
+        let cursor = self.create_cursor;
+        let field = self.create_field_value_mut();
+        let index = byte_index(field, cursor);
+        field.insert(index, c);
+        self.create_cursor += 1;
+        self.create_error = None;
+    }
+
+    /// Delete the character before the cursor in the active field
+    pub fn create_backspace(&mut self) {
+        if self.create_cursor == 0 {
+            return;
+        }
+
+        self.create_history_index = None;
+        let cursor = self.create_cursor - 1;
+        let field = self.create_field_value_mut();
+        let index = byte_index(field, cursor);
+        field.remove(index);
+        self.create_cursor = cursor;
+        self.create_error = None;
+    }
+
+    /// Delete the character under the cursor in the active field
+    pub fn create_delete(&mut self) {
+        let cursor = self.create_cursor;
+        let field = self.create_field_value_mut();
+        if cursor < field.chars().count() {
+            let index = byte_index(field, cursor);
+            field.remove(index);
+            self.create_history_index = None;
+            self.create_error = None;
+        }
+    }
+
+    pub fn create_cursor_left(&mut self) {
+        self.create_cursor = self.create_cursor.saturating_sub(1);
+    }
+
+    pub fn create_cursor_right(&mut self) {
+        let len = self.create_field_value().chars().count();
+        self.create_cursor = (self.create_cursor + 1).min(len);
+    }
+
+    pub fn create_cursor_home(&mut self) {
+        self.create_cursor = 0;
+    }
+
+    pub fn create_cursor_end(&mut self) {
+        self.create_cursor = self.create_field_value().chars().count();
+    }
+
+    fn focus_step_field(&mut self) {
+        match self.create_step {
+            0 => self.focus_create_field(CreateField::Name),
+            1 => self.focus_create_field(CreateField::Description),
+            _ => {}
+        }
+    }
+
+    fn focus_create_field(&mut self, field: CreateField) {
+        self.create_field = field;
+        self.create_cursor = self.create_field_value().chars().count();
+        self.create_history_index = None;
+    }
+
+    /// Text of the active wizard field
+    pub fn create_field_value(&self) -> &str {
+        self.field_value(self.create_field)
+    }
+
+    fn field_value(&self, field: CreateField) -> &str {
+        match field {
+            CreateField::Name => &self.create_name,
+            CreateField::Path => &self.create_path,
+            CreateField::Description => &self.create_description,
+        }
+    }
+
+    fn create_field_value_mut(&mut self) -> &mut String {
+        match self.create_field {
+            CreateField::Name => &mut self.create_name,
+            CreateField::Path => &mut self.create_path,
+            CreateField::Description => &mut self.create_description,
+        }
+    }
+
+    /// Check the name and path entered on the first wizard step
+    fn validate_create_location(&self) -> Result<()> {
+        let context = ErrorContext::new().with_context("Workspace creation");
+
+        if self.create_name.trim().is_empty() {
+            return Err(PixlieError::validation(
+                "name",
+                "Workspace name cannot be empty",
+                context,
+            ));
+        }
+
+        let path_str = self.create_path.trim();
+        if path_str.is_empty() {
+            return Err(PixlieError::validation(
+                "path",
+                "Workspace path cannot be empty",
+                context,
+            ));
+        }
+
+        // A relative name like "sales" has an empty parent, which stands for
+        // the current directory
+        let path = PathBuf::from(path_str);
+        if path.exists() {
+            if !path.is_dir() {
+                return Err(PixlieError::validation(
+                    "path",
+                    "Workspace path is not a directory",
+                    context,
+                ));
+            }
+
+            if path.join(".pixlie-workspace.toml").exists() {
+                return Err(PixlieError::validation(
+                    "path",
+                    "A workspace already exists at this path",
+                    context,
+                ));
+            }
+        } else if !path
+            .parent()
+            .is_some_and(|parent| parent.as_os_str().is_empty() || parent.is_dir())
+        {
+            return Err(PixlieError::validation(
+                "path",
+                "Parent directory of the workspace path does not exist",
+                context,
+            ));
         }
+
+        Ok(())
     }
 
     pub fn mode(&self) -> &WorkspaceManagerMode {
@@ -291,7 +668,7 @@ impl WorkspaceManager {
         let main_block = Block::default()
             .title("Workspace Manager")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan));
+            .border_style(Style::default().fg(self.theme.accent));
 
         frame.render_widget(main_block, popup_area);
 
@@ -327,7 +704,7 @@ impl WorkspaceManager {
         };
 
         let header = Paragraph::new(vec![
-            Line::from(Span::styled(current_text, Style::default().fg(Color::Green))),
+            Line::from(Span::styled(current_text, Style::default().fg(self.theme.success))),
             Line::from(""),
             Line::from("↑/↓: Navigate • Enter: Open • S: Switch • D: Details • E: Edit • Del: Delete • N: New • Esc: Close"),
         ])
@@ -347,19 +724,18 @@ impl WorkspaceManager {
             let items: Vec<ListItem> = self
                 .workspaces
                 .iter()
-                .enumerate()
-                .map(|(_i, workspace)| {
+                .map(|workspace| {
                     let mut lines = vec![Line::from(Span::styled(
                         workspace.name.clone(),
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.highlight)
                             .add_modifier(Modifier::BOLD),
                     ))];
 
                     if let Some(desc) = &workspace.description {
                         lines.push(Line::from(Span::styled(
                             format!("  {}", desc),
-                            Style::default().fg(Color::Gray),
+                            Style::default().fg(self.theme.muted),
                         )));
                     }
 
@@ -383,7 +759,7 @@ impl WorkspaceManager {
 
                     lines.push(Line::from(Span::styled(
                         format!("  {}", info_parts.join(" • ")),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(self.theme.muted),
                     )));
 
                     // Add current workspace indicator
@@ -394,7 +770,7 @@ impl WorkspaceManager {
                                 Line::from(Span::styled(
                                     "● CURRENT",
                                     Style::default()
-                                        .fg(Color::Green)
+                                        .fg(self.theme.success)
                                         .add_modifier(Modifier::BOLD),
                                 )),
                             );
@@ -411,7 +787,7 @@ impl WorkspaceManager {
                         .title("Available Workspaces")
                         .borders(Borders::ALL),
                 )
-                .highlight_style(Style::default().bg(Color::DarkGray))
+                .highlight_style(Style::default().bg(self.theme.selection))
                 .highlight_symbol("❯ ");
 
             frame.render_stateful_widget(list, chunks[1], &mut self.selected);
@@ -420,7 +796,7 @@ impl WorkspaceManager {
         // Actions
         let actions = Paragraph::new("N: New workspace • I: Import • G: Settings • Esc: Close")
             .block(Block::default().borders(Borders::TOP))
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.muted));
 
         frame.render_widget(actions, chunks[2]);
     }
@@ -441,7 +817,7 @@ impl WorkspaceManager {
                 .block(Block::default().borders(Borders::BOTTOM))
                 .style(
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(self.theme.highlight)
                         .add_modifier(Modifier::BOLD),
                 );
             frame.render_widget(title, chunks[0]);
@@ -449,30 +825,30 @@ impl WorkspaceManager {
             // Details
             let mut details = vec![
                 Line::from(vec![
-                    Span::styled("Name: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Name: ", Style::default().fg(self.theme.accent)),
                     Span::raw(workspace.name.clone()),
                 ]),
                 Line::from(vec![
-                    Span::styled("Path: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Path: ", Style::default().fg(self.theme.accent)),
                     Span::raw(workspace.path.display().to_string()),
                 ]),
             ];
 
             if let Some(desc) = &workspace.description {
                 details.push(Line::from(vec![
-                    Span::styled("Description: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Description: ", Style::default().fg(self.theme.accent)),
                     Span::raw(desc.clone()),
                 ]));
             }
 
             details.push(Line::from(vec![
-                Span::styled("Objectives: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Objectives: ", Style::default().fg(self.theme.accent)),
                 Span::raw(workspace.objectives_count.to_string()),
             ]));
 
             if let Some(modified) = workspace.last_modified {
                 details.push(Line::from(vec![
-                    Span::styled("Last Modified: ", Style::default().fg(Color::Cyan)),
+                    Span::styled("Last Modified: ", Style::default().fg(self.theme.accent)),
                     Span::raw(modified.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
                 ]));
             }
@@ -487,14 +863,14 @@ impl WorkspaceManager {
             let actions =
                 Paragraph::new("Enter: Open • S: Switch • E: Edit • Del: Delete • Esc: Back")
                     .block(Block::default().borders(Borders::TOP))
-                    .style(Style::default().fg(Color::Gray));
+                    .style(Style::default().fg(self.theme.muted));
 
             frame.render_widget(actions, chunks[2]);
         }
     }
 
     fn render_create_mode(&self, frame: &mut Frame, area: Rect) {
-        let step_titles = vec![
+        let step_titles = [
             "Step 1: Location and Name",
             "Step 2: Description and Tags",
             "Step 3: Default Database",
@@ -520,7 +896,7 @@ impl WorkspaceManager {
         .block(Block::default().borders(Borders::BOTTOM))
         .style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(self.theme.highlight)
                 .add_modifier(Modifier::BOLD),
         );
         frame.render_widget(progress, chunks[0]);
@@ -536,17 +912,16 @@ impl WorkspaceManager {
         }
 
         // Actions
-        let actions = if self.create_step == 0 {
-            "Tab: Next field • Enter: Next step • Esc: Cancel"
-        } else if self.create_step == 4 {
-            "Tab: Next field • Enter: Create workspace • Backspace: Previous step • Esc: Cancel"
-        } else {
-            "Tab: Next field • Enter: Next step • Backspace: Previous step • Esc: Cancel"
+        let actions = match self.create_step {
+            0 => "Tab: Next field • ↑/↓: History • Enter: Next step • Esc: Cancel",
+            1 => "↑/↓: History • Enter: Next step • Shift+Tab: Previous step • Esc: Cancel",
+            4 => "Enter: Create workspace • Backspace: Previous step • Esc: Cancel",
+            _ => "Enter: Next step • Backspace: Previous step • Esc: Cancel",
         };
 
         let actions_widget = Paragraph::new(actions)
             .block(Block::default().borders(Borders::TOP))
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.muted));
 
         frame.render_widget(actions_widget, chunks[2]);
     }
@@ -561,32 +936,25 @@ impl WorkspaceManager {
             ])
             .split(area);
 
-        // Name input
-        let name_input = Paragraph::new(self.create_name.clone())
-            .block(
-                Block::default()
-                    .title("Workspace Name")
-                    .borders(Borders::ALL),
-            )
-            .style(Style::default().fg(Color::White).bg(Color::DarkGray));
-        frame.render_widget(name_input, chunks[0]);
-
-        // Path input
-        let path_input = Paragraph::new(self.create_path.clone())
-            .block(
-                Block::default()
-                    .title("Workspace Path")
-                    .borders(Borders::ALL),
-            )
-            .style(Style::default().fg(Color::White));
-        frame.render_widget(path_input, chunks[1]);
+        self.render_create_field(frame, chunks[0], "Workspace Name", CreateField::Name);
+        self.render_create_field(frame, chunks[1], "Workspace Path", CreateField::Path);
+
+        // Instructions, preceded by any validation error
+        let mut lines = Vec::new();
+        if let Some(error) = &self.create_error {
+            lines.push(Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(self.theme.error),
+            )));
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(
+            "Choose a name and location for your new workspace. The workspace name will be used for display and the path determines where workspace files are stored.",
+        ));
 
-        // Instructions
-        let instructions = Paragraph::new(
-            "Choose a name and location for your new workspace. The workspace name will be used for display and the path determines where workspace files are stored."
-        )
-        .block(Block::default().title("Instructions").borders(Borders::ALL))
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        let instructions = Paragraph::new(lines)
+            .block(Block::default().title("Instructions").borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: true });
         frame.render_widget(instructions, chunks[2]);
     }
 
@@ -599,16 +967,12 @@ impl WorkspaceManager {
             ])
             .split(area);
 
-        // Description input
-        let desc_input = Paragraph::new(self.create_description.clone())
-            .block(
-                Block::default()
-                    .title("Description (Optional)")
-                    .borders(Borders::ALL),
-            )
-            .style(Style::default().fg(Color::White).bg(Color::DarkGray))
-            .wrap(ratatui::widgets::Wrap { trim: true });
-        frame.render_widget(desc_input, chunks[0]);
+        self.render_create_field(
+            frame,
+            chunks[0],
+            "Description (Optional)",
+            CreateField::Description,
+        );
 
         // Instructions
         let instructions = Paragraph::new(
@@ -619,6 +983,55 @@ impl WorkspaceManager {
         frame.render_widget(instructions, chunks[1]);
     }
 
+    /// Render a wizard text field, highlighting it and placing the cursor when active
+    fn render_create_field(&self, frame: &mut Frame, area: Rect, title: &str, field: CreateField) {
+        let value = self.field_value(field);
+        let active = self.create_field == field;
+
+        let (style, border_style) = if active {
+            (
+                Style::default()
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.selection),
+                Style::default().fg(self.theme.accent),
+            )
+        } else {
+            (
+                Style::default().fg(self.theme.foreground),
+                Style::default().fg(self.theme.muted),
+            )
+        };
+
+        // Scroll horizontally so the cursor stays inside the box, measuring
+        // in terminal columns so wide characters keep the cursor aligned
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let cursor = if active {
+            value
+                .chars()
+                .take(self.create_cursor)
+                .map(|c| c.width().unwrap_or(0))
+                .sum()
+        } else {
+            0
+        };
+        let offset = (cursor + 1).saturating_sub(inner_width);
+
+        let input = Paragraph::new(value.to_string())
+            .block(
+                Block::default()
+                    .title(title.to_string())
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            )
+            .style(style)
+            .scroll((0, offset as u16));
+        frame.render_widget(input, area);
+
+        if active {
+            frame.set_cursor(area.x + 1 + (cursor - offset) as u16, area.y + 1);
+        }
+    }
+
     fn render_create_step_3(&self, frame: &mut Frame, area: Rect) {
         let instructions = Paragraph::new(
             "Configure a default database for this workspace (optional). You can always add databases later through the workspace settings."
@@ -663,7 +1076,7 @@ impl WorkspaceManager {
             .block(Block::default().borders(Borders::BOTTOM))
             .style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(self.theme.highlight)
                     .add_modifier(Modifier::BOLD),
             );
         frame.render_widget(title, chunks[0]);
@@ -671,13 +1084,21 @@ impl WorkspaceManager {
         // Name input
         let name_input = Paragraph::new(self.edit_name.clone())
             .block(Block::default().title("Name").borders(Borders::ALL))
-            .style(Style::default().fg(Color::White).bg(Color::DarkGray));
+            .style(
+                Style::default()
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.selection),
+            );
         frame.render_widget(name_input, chunks[1]);
 
         // Description input
         let desc_input = Paragraph::new(self.edit_description.clone())
             .block(Block::default().title("Description").borders(Borders::ALL))
-            .style(Style::default().fg(Color::White).bg(Color::DarkGray))
+            .style(
+                Style::default()
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.selection),
+            )
             .wrap(ratatui::widgets::Wrap { trim: true });
         frame.render_widget(desc_input, chunks[2]);
 
@@ -688,13 +1109,13 @@ impl WorkspaceManager {
                     .title("Path (Read-only)")
                     .borders(Borders::ALL),
             )
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.muted));
         frame.render_widget(path_display, chunks[3]);
 
         // Actions
         let actions = Paragraph::new("Tab: Next field • Enter: Save changes • Esc: Cancel")
             .block(Block::default().borders(Borders::TOP))
-            .style(Style::default().fg(Color::Gray));
+            .style(Style::default().fg(self.theme.muted));
         frame.render_widget(actions, chunks[4]);
     }
 
@@ -712,17 +1133,22 @@ impl WorkspaceManager {
             // Title
             let title = Paragraph::new("Delete Workspace")
                 .block(Block::default().borders(Borders::BOTTOM))
-                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+                .style(
+                    Style::default()
+                        .fg(self.theme.error)
+                        .add_modifier(Modifier::BOLD),
+                );
             frame.render_widget(title, chunks[0]);
 
             // Warning
-            let warning = Paragraph::new(vec![
+            let mut warning_lines =
+                vec![
                 Line::from(Span::styled(
                     "⚠️  WARNING: This action cannot be undone!",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    Style::default().fg(self.theme.error).add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
-                Line::from(format!("You are about to delete the workspace:")),
+                Line::from("You are about to delete the workspace:"),
                 Line::from(format!("Name: {}", workspace.name)),
                 Line::from(format!("Path: {}", workspace.path.display())),
                 Line::from(""),
@@ -733,23 +1159,212 @@ impl WorkspaceManager {
                     "Your data files will NOT be deleted, only the workspace configuration.",
                 ),
                 Line::from(""),
-                Line::from("Are you sure you want to continue?"),
-            ])
-            .block(
-                Block::default()
-                    .title("Confirmation Required")
-                    .borders(Borders::ALL),
-            )
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            ];
+
+            if self.is_selected_current() {
+                warning_lines.push(Line::from(Span::styled(
+                    "This is the currently open workspace. It will be closed after deletion.",
+                    Style::default()
+                        .fg(self.theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                )));
+                warning_lines.push(Line::from(""));
+            }
+
+            warning_lines.push(Line::from("Are you sure you want to continue?"));
+
+            let warning = Paragraph::new(warning_lines)
+                .block(
+                    Block::default()
+                        .title("Confirmation Required")
+                        .borders(Borders::ALL),
+                )
+                .wrap(ratatui::widgets::Wrap { trim: true });
 
             frame.render_widget(warning, chunks[1]);
 
             // Actions
             let actions = Paragraph::new("Y: Yes, delete workspace • N: No, cancel • Esc: Cancel")
                 .block(Block::default().borders(Borders::TOP))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(self.theme.muted));
 
             frame.render_widget(actions, chunks[2]);
         }
     }
 }
+
+/// Byte offset of the character at `char_index`, or the end of the string
+fn byte_index(value: &str, char_index: usize) -> usize {
+    value
+        .char_indices()
+        .nth(char_index)
+        .map_or(value.len(), |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_delete_selected_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_dir = temp_dir.path().join("analysis");
+        std::fs::create_dir(&workspace_dir).unwrap();
+
+        let workspace_file = workspace_dir.join(".pixlie-workspace.toml");
+        let data_file = workspace_dir.join("data.db");
+        std::fs::write(&workspace_file, "[metadata]\nname = \"analysis\"\n").unwrap();
+        std::fs::write(&data_file, "").unwrap();
+
+        let mut manager = WorkspaceManager::new();
+        let mut workspaces = Vec::new();
+        manager
+            .scan_directory_for_workspaces(temp_dir.path(), &mut workspaces)
+            .await
+            .unwrap();
+        assert_eq!(workspaces.len(), 1);
+        manager.workspaces = workspaces;
+        manager.selected.select(Some(0));
+
+        manager.enter_delete_mode();
+        assert_eq!(manager.mode(), &WorkspaceManagerMode::Delete);
+
+        let deleted = manager.delete_selected_workspace().await.unwrap();
+        assert_eq!(deleted.unwrap().path, workspace_dir);
+
+        assert!(!workspace_file.exists());
+        assert!(data_file.exists());
+        assert!(manager.workspaces.is_empty());
+        assert!(manager.selected_workspace().is_none());
+        assert_eq!(manager.mode(), &WorkspaceManagerMode::List);
+    }
+
+    #[test]
+    fn test_create_field_editing() {
+        let mut manager = WorkspaceManager::new();
+        manager.enter_create_mode();
+
+        for c in "Sals".chars() {
+            manager.create_input_char(c);
+        }
+        manager.create_cursor_left();
+        manager.create_input_char('e');
+        manager.create_cursor_end();
+        manager.create_backspace();
+        assert_eq!(manager.create_name, "Sale");
+
+        manager.create_cursor_home();
+        manager.create_delete();
+        assert_eq!(manager.create_name, "ale");
+
+        manager.next_create_field();
+        assert_eq!(manager.create_field, CreateField::Path);
+        assert_eq!(manager.create_cursor, manager.create_path.chars().count());
+    }
+
+    #[test]
+    fn test_create_step_validates_location() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = WorkspaceManager::new();
+        manager.enter_create_mode();
+
+        // Empty name blocks the step
+        manager.create_path = temp_dir.path().to_string_lossy().to_string();
+        manager.next_create_step();
+        assert_eq!(manager.create_step, 0);
+        assert!(manager.create_error.is_some());
+
+        // Missing parent directory blocks the step
+        manager.create_name = "Sales".to_string();
+        manager.create_path = temp_dir
+            .path()
+            .join("missing")
+            .join("nested")
+            .to_string_lossy()
+            .to_string();
+        manager.next_create_step();
+        assert_eq!(manager.create_step, 0);
+
+        // A new directory under an existing parent is accepted
+        manager.create_path = temp_dir.path().join("sales").to_string_lossy().to_string();
+        manager.next_create_step();
+        assert_eq!(manager.create_step, 1);
+        assert!(manager.create_error.is_none());
+        assert_eq!(manager.create_field, CreateField::Description);
+    }
+
+    #[test]
+    fn test_create_accepts_relative_path() {
+        let mut manager = WorkspaceManager::new();
+        manager.enter_create_mode();
+        manager.create_name = "Sales".to_string();
+        manager.create_path = format!("pixlie-test-{}", uuid::Uuid::new_v4());
+
+        assert!(manager.validate_create_location().is_ok());
+    }
+
+    #[test]
+    fn test_create_field_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = WorkspaceManager::new();
+
+        for name in ["Sales", "Marketing"] {
+            manager.enter_create_mode();
+            manager.create_name = name.to_string();
+            manager.create_path = temp_dir.path().join(name).to_string_lossy().to_string();
+            manager.next_create_step();
+        }
+
+        manager.enter_create_mode();
+        manager.create_input_char('F');
+        manager.create_history_previous();
+        assert_eq!(manager.create_name, "Marketing");
+        manager.create_history_previous();
+        manager.create_history_previous();
+        assert_eq!(manager.create_name, "Sales");
+        assert_eq!(manager.create_cursor, 5);
+
+        // Stepping past the newest entry restores what was typed
+        manager.create_history_next();
+        manager.create_history_next();
+        assert_eq!(manager.create_name, "F");
+
+        // Each field has its own history
+        manager.next_create_field();
+        manager.create_history_previous();
+        assert!(manager.create_path.ends_with("Marketing"));
+    }
+
+    #[tokio::test]
+    async fn test_scan_nested_workspaces_without_duplicates() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        let write_workspace = |dir: &PathBuf, name: &str| {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(
+                dir.join(".pixlie-workspace.toml"),
+                format!("[metadata]\nname = \"{}\"\n", name),
+            )
+            .unwrap();
+        };
+
+        write_workspace(&root.join("projects").join("sales"), "sales");
+        write_workspace(
+            &root.join("projects").join("deep").join("a").join("b"),
+            "too-deep",
+        );
+        write_workspace(&root.join(".hidden").join("secret"), "hidden");
+        write_workspace(&root.join("home"), "home");
+
+        // The second root lies under the first, like a home directory under the cwd
+        let manager = WorkspaceManager::new();
+        let workspaces = manager
+            .scan_roots(&[root.clone(), root.join("home")])
+            .await
+            .unwrap();
+
+        let names: Vec<_> = workspaces.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, ["home", "sales"]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::config::WorkspaceConfig;
+use crate::tui::Theme;
 use crate::{ErrorContext, PixlieError, Result};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
@@ -18,6 +19,13 @@ pub struct WorkspacePicker {
     selected: ListState,
     show_browse: bool,
     browse_path: String,
+    theme: Theme,
+}
+
+impl Default for WorkspacePicker {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl WorkspacePicker {
@@ -30,6 +38,7 @@ impl WorkspacePicker {
             selected,
             show_browse: false,
             browse_path: String::new(),
+            theme: Theme::default(),
         }
     }
 
@@ -58,7 +67,7 @@ impl WorkspacePicker {
         }
 
         // Sort by last modified date (most recent first)
-        workspaces.sort_by(|a, b| b.last_modified.cmp(&a.last_modified));
+        workspaces.sort_by_key(|workspace| std::cmp::Reverse(workspace.last_modified));
 
         self.workspaces = workspaces;
 
@@ -135,6 +144,10 @@ impl WorkspacePicker {
         })
     }
 
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     pub fn next(&mut self) {
         let i = match self.selected.selected() {
             Some(i) => {
@@ -199,7 +212,7 @@ impl WorkspacePicker {
         let main_block = Block::default()
             .title("Workspace Picker")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan));
+            .border_style(Style::default().fg(self.theme.accent));
 
         frame.render_widget(main_block, popup_area);
 
@@ -249,14 +262,14 @@ impl WorkspacePicker {
                     let mut lines = vec![Line::from(Span::styled(
                         workspace.name.clone(),
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.highlight)
                             .add_modifier(Modifier::BOLD),
                     ))];
 
                     if let Some(desc) = &workspace.description {
                         lines.push(Line::from(Span::styled(
                             format!("  {}", desc),
-                            Style::default().fg(Color::Gray),
+                            Style::default().fg(self.theme.muted),
                         )));
                     }
 
@@ -280,7 +293,7 @@ impl WorkspacePicker {
 
                     lines.push(Line::from(Span::styled(
                         format!("  {}", info_parts.join(" • ")),
-                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(self.theme.muted),
                     )));
 
                     ListItem::new(lines)
@@ -293,7 +306,7 @@ impl WorkspacePicker {
                         .title("Recent Workspaces")
                         .borders(Borders::ALL),
                 )
-                .highlight_style(Style::default().bg(Color::DarkGray))
+                .highlight_style(Style::default().bg(self.theme.selection))
                 .highlight_symbol("❯ ");
 
             frame.render_stateful_widget(list, chunks[1], &mut self.selected);
@@ -303,7 +316,7 @@ impl WorkspacePicker {
         let actions =
             Paragraph::new("B: Browse for workspace • N: Create new workspace • Q: Cancel")
                 .block(Block::default().borders(Borders::TOP))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(self.theme.muted));
 
         frame.render_widget(actions, chunks[2]);
     }
@@ -327,7 +340,11 @@ impl WorkspacePicker {
         // Path input
         let path_input = Paragraph::new(self.browse_path.clone())
             .block(Block::default().title("Path").borders(Borders::ALL))
-            .style(Style::default().fg(Color::White).bg(Color::DarkGray));
+            .style(
+                Style::default()
+                    .fg(self.theme.foreground)
+                    .bg(self.theme.selection),
+            );
         frame.render_widget(path_input, chunks[1]);
 
         // Directory contents (placeholder for now)
@@ -342,7 +359,7 @@ impl WorkspacePicker {
         let actions =
             Paragraph::new("Enter: Select current path • Esc: Back to workspace list • Q: Cancel")
                 .block(Block::default().borders(Borders::TOP))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(self.theme.muted));
         frame.render_widget(actions, chunks[3]);
     }
 }
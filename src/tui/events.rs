@@ -25,38 +25,16 @@ impl EventHandler {
                     if event {
                         match event::read() {
                             Ok(CrosstermEvent::Key(key)) => {
-                                // Handle special key combinations
-                                if key.modifiers.contains(KeyModifiers::CONTROL) {
-                                    match key.code {
-                                        KeyCode::Char('c') => {
-                                            let _ = event_sender.send(Event::Quit);
-                                            break;
-                                        }
-                                        KeyCode::Char('q') => {
-                                            let _ = event_sender.send(Event::Quit);
-                                            break;
-                                        }
-                                        KeyCode::Char(',') => {
-                                            // Send Ctrl+, as a special settings key
-                                            let _ = event_sender.send(Event::Key(KeyEvent::new(
-                                                KeyCode::F(12), // Use F12 as internal settings key
-                                                KeyModifiers::NONE,
-                                            )));
-                                        }
-                                        KeyCode::Char('w') => {
-                                            // Send Ctrl+W as a special workspace manager key
-                                            let _ = event_sender.send(Event::Key(KeyEvent::new(
-                                                KeyCode::F(11), // Use F11 as internal workspace manager key
-                                                KeyModifiers::NONE,
-                                            )));
-                                        }
-                                        _ => {
-                                            let _ = event_sender.send(Event::Key(key));
-                                        }
-                                    }
-                                } else {
-                                    let _ = event_sender.send(Event::Key(key));
+                                // Ctrl+C always quits; other shortcuts are resolved
+                                // from the configured keymap by the app
+                                if key.modifiers.contains(KeyModifiers::CONTROL)
+                                    && key.code == KeyCode::Char('c')
+                                {
+                                    let _ = event_sender.send(Event::Quit);
+                                    break;
                                 }
+
+                                let _ = event_sender.send(Event::Key(key));
                             }
                             Ok(CrosstermEvent::Resize(w, h)) => {
                                 let _ = event_sender.send(Event::Resize(w, h));
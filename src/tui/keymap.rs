@@ -0,0 +1,134 @@
+use crate::config::keys::KeyBinding;
+use crate::config::ShortcutsConfig;
+use crate::{ErrorContext, PixlieError, Result};
+use crossterm::event::KeyEvent;
+
+/// Actions that can be bound to keyboard shortcuts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    Quit,
+    NewObjective,
+    DeleteObjective,
+    ToggleHistory,
+    SaveSession,
+    LoadSession,
+    NextObjective,
+    PrevObjective,
+    Settings,
+    WorkspaceManager,
+    Send,
+    ClearInput,
+    NavUp,
+    NavDown,
+    NavLeft,
+    NavRight,
+}
+
+impl ShortcutAction {
+    fn from_name(name: &str) -> Option<Self> {
+        let action = match name {
+            "quit" => Self::Quit,
+            "new_objective" => Self::NewObjective,
+            "delete_objective" => Self::DeleteObjective,
+            "toggle_history" => Self::ToggleHistory,
+            "save_session" => Self::SaveSession,
+            "load_session" => Self::LoadSession,
+            "next_objective" => Self::NextObjective,
+            "prev_objective" => Self::PrevObjective,
+            "settings" => Self::Settings,
+            "workspace_manager" => Self::WorkspaceManager,
+            "send" => Self::Send,
+            "clear_input" => Self::ClearInput,
+            "nav_up" => Self::NavUp,
+            "nav_down" => Self::NavDown,
+            "nav_left" => Self::NavLeft,
+            "nav_right" => Self::NavRight,
+            _ => return None,
+        };
+        Some(action)
+    }
+}
+
+/// Key bindings resolved from the shortcuts configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, ShortcutAction)>,
+}
+
+impl Keymap {
+    /// Build a keymap from the configured shortcut strings
+    pub fn from_config(config: &ShortcutsConfig) -> Result<Self> {
+        let mut bindings = Vec::new();
+
+        for (name, value) in config.bindings() {
+            let binding = KeyBinding::parse(value).ok_or_else(|| {
+                PixlieError::configuration(
+                    format!("Unknown key combination '{}' for shortcuts.{}", value, name),
+                    ErrorContext::new().with_context("Keymap construction"),
+                )
+            })?;
+
+            if let Some(action) = ShortcutAction::from_name(name) {
+                bindings.push((binding, action));
+            }
+        }
+
+        Ok(Self { bindings })
+    }
+
+    /// Action bound to a key event, if any
+    ///
+    /// When several actions share a key, the first in configuration order wins.
+    pub fn action_for(&self, event: &KeyEvent) -> Option<ShortcutAction> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding.matches(event))
+            .map(|(_, action)| *action)
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&ShortcutsConfig::default()).unwrap_or(Self {
+            bindings: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_default_keymap() {
+        let keymap = Keymap::default();
+
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(&ctrl_q), Some(ShortcutAction::Quit));
+
+        let ctrl_w = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(
+            keymap.action_for(&ctrl_w),
+            Some(ShortcutAction::WorkspaceManager)
+        );
+
+        let plain_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(keymap.action_for(&plain_q), None);
+    }
+
+    #[test]
+    fn test_remapped_shortcut() {
+        let config = ShortcutsConfig {
+            quit: "Alt+X".to_string(),
+            ..Default::default()
+        };
+        let keymap = Keymap::from_config(&config).unwrap();
+
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(&ctrl_q), None);
+
+        let alt_x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT);
+        assert_eq!(keymap.action_for(&alt_x), Some(ShortcutAction::Quit));
+    }
+}
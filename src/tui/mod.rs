@@ -1,8 +1,12 @@
 pub mod app;
 pub mod components;
 pub mod events;
+pub mod keymap;
 pub mod layout;
+pub mod theme;
 
 pub use app::{App, AppMode, SettingsTab};
 pub use events::{Event, EventHandler};
+pub use keymap::{Keymap, ShortcutAction};
 pub use layout::Layout;
+pub use theme::Theme;
@@ -0,0 +1,186 @@
+use crate::config::{parse_theme_color, UiConfig};
+use crate::{ErrorContext, PixlieError, Result};
+use ratatui::style::Color;
+
+/// Resolved color palette used by TUI components
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub foreground: Color,
+    pub background: Color,
+    pub accent: Color,
+    pub highlight: Color,
+    pub muted: Color,
+    pub success: Color,
+    pub error: Color,
+    pub selection: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            foreground: Color::White,
+            background: Color::Reset,
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            muted: Color::Gray,
+            success: Color::Green,
+            error: Color::Red,
+            selection: Color::DarkGray,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            foreground: Color::Black,
+            background: Color::White,
+            accent: Color::Blue,
+            highlight: Color::Magenta,
+            muted: Color::DarkGray,
+            success: Color::Green,
+            error: Color::Red,
+            selection: Color::Gray,
+        }
+    }
+
+    /// Look up a built-in theme by name
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            // Terminal background detection isn't available, so auto uses dark
+            "dark" | "auto" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    /// Resolve the theme selected by the UI configuration
+    ///
+    /// Built-in names take precedence; otherwise the custom theme of that name
+    /// is applied on top of the dark palette.
+    pub fn from_config(ui: &UiConfig) -> Result<Self> {
+        if let Some(theme) = Self::builtin(&ui.theme) {
+            return Ok(theme);
+        }
+
+        let custom = ui.themes.get(&ui.theme).ok_or_else(|| {
+            PixlieError::configuration(
+                format!("Unknown theme '{}'", ui.theme),
+                ErrorContext::new().with_context("Theme resolution"),
+            )
+        })?;
+
+        let base = Self::dark();
+        let resolve = |field: &str, value: Option<&String>, fallback: Color| -> Result<Color> {
+            let Some(value) = value else {
+                return Ok(fallback);
+            };
+
+            parse_theme_color(value).ok_or_else(|| {
+                PixlieError::configuration(
+                    format!(
+                        "Invalid color '{}' for ui.themes.{}.{}",
+                        value, ui.theme, field
+                    ),
+                    ErrorContext::new().with_context("Theme resolution"),
+                )
+            })
+        };
+
+        Ok(Self {
+            foreground: resolve("foreground", custom.foreground.as_ref(), base.foreground)?,
+            background: resolve("background", custom.background.as_ref(), base.background)?,
+            accent: resolve("accent", custom.accent.as_ref(), base.accent)?,
+            highlight: resolve("highlight", custom.highlight.as_ref(), base.highlight)?,
+            muted: resolve("muted", custom.muted.as_ref(), base.muted)?,
+            success: resolve("success", custom.success.as_ref(), base.success)?,
+            error: resolve("error", custom.error.as_ref(), base.error)?,
+            selection: resolve("selection", custom.selection.as_ref(), base.selection)?,
+        })
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ThemeConfig;
+
+    #[test]
+    fn test_builtin_themes() {
+        let mut ui = UiConfig::default();
+        assert_eq!(Theme::from_config(&ui).unwrap(), Theme::dark());
+
+        ui.theme = "light".to_string();
+        assert_eq!(Theme::from_config(&ui).unwrap(), Theme::light());
+    }
+
+    #[test]
+    fn test_custom_theme_overrides_dark() {
+        let mut ui = UiConfig {
+            theme: "ocean".to_string(),
+            ..Default::default()
+        };
+        ui.themes.insert(
+            "ocean".to_string(),
+            ThemeConfig {
+                accent: Some("#0088cc".to_string()),
+                highlight: Some("lightblue".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let theme = Theme::from_config(&ui).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0x00, 0x88, 0xcc));
+        assert_eq!(theme.highlight, Color::LightBlue);
+        assert_eq!(theme.error, Theme::dark().error);
+    }
+
+    #[test]
+    fn test_custom_theme_sets_every_color() {
+        let mut ui = UiConfig {
+            theme: "mono".to_string(),
+            ..Default::default()
+        };
+        ui.themes.insert(
+            "mono".to_string(),
+            ThemeConfig {
+                foreground: Some("0".to_string()),
+                background: Some("1".to_string()),
+                accent: Some("2".to_string()),
+                highlight: Some("3".to_string()),
+                muted: Some("4".to_string()),
+                success: Some("5".to_string()),
+                error: Some("6".to_string()),
+                selection: Some("7".to_string()),
+            },
+        );
+
+        let theme = Theme::from_config(&ui).unwrap();
+        assert_eq!(
+            theme,
+            Theme {
+                foreground: Color::Indexed(0),
+                background: Color::Indexed(1),
+                accent: Color::Indexed(2),
+                highlight: Color::Indexed(3),
+                muted: Color::Indexed(4),
+                success: Color::Indexed(5),
+                error: Color::Indexed(6),
+                selection: Color::Indexed(7),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_theme() {
+        let ui = UiConfig {
+            theme: "missing".to_string(),
+            ..Default::default()
+        };
+        assert!(Theme::from_config(&ui).is_err());
+    }
+}